@@ -8,10 +8,13 @@ use core::{
 
 use crate::{
     eye,
-    num::{Abs, One, Zero},
-    Matrix,
+    num::{Abs, Epsilon, FromUsize, Ln, One, Sqrt, Zero},
+    Matrix, Vector,
 };
 
+/// Upper bound on the number of Denman-Beavers iterations in `sqrtm`.
+const SQRTM_MAX_ITERATIONS: usize = 64;
+
 impl<const D: usize, T> Matrix<D, D, T>
 where
     T: Abs
@@ -28,7 +31,52 @@ where
         + Div<Output = T>,
 {
     pub fn inv(&self) -> Option<Self> {
-        let (mut L, mut U, P) = self.lu();
+        let (L, U, P) = self.lu();
+        Self::invert_lu(L, U, P)
+    }
+
+    /// Compute the right division `self * other⁻¹`.
+    ///
+    /// Returns `None` if `other` is singular. See also the `/` operator, which
+    /// panics instead.
+    pub fn try_div(&self, other: &Self) -> Option<Self> {
+        Some(self * other.inv()?)
+    }
+
+    /// Compute the left division `self⁻¹ B` (MATLAB's `A \ B`).
+    ///
+    /// The system `A X = B` is solved through the LU factorization instead of
+    /// forming the inverse explicitly. Returns `None` if the matrix is
+    /// singular.
+    pub fn left_divide<const P: usize>(&self, b: &Matrix<D, P, T>) -> Option<Matrix<D, P, T>> {
+        self.solve(b)
+    }
+
+    /// Solve the linear system `A X = B` using the LU factorization.
+    ///
+    /// The permutation is applied to `B`, followed by forward substitution with
+    /// `L` and back substitution with `U`. Returns `None` if `U` has a zero on
+    /// its diagonal, i.e. the matrix is singular.
+    pub fn solve<const P: usize>(&self, b: &Matrix<D, P, T>) -> Option<Matrix<D, P, T>> {
+        let (L, U, perm) = self.lu();
+        Self::lu_substitute(&L, &U, &(perm * b))
+    }
+
+    /// Compute the inverse, treating the matrix as singular if any LU pivot
+    /// satisfies `|pivot| <= eps`.
+    ///
+    /// [`inv`][Self::inv] only rejects exactly-zero pivots, so a nearly
+    /// singular matrix can produce a huge, meaningless inverse. Returns `None`
+    /// if the matrix is singular to within `eps`.
+    pub fn inv_tol(&self, eps: T) -> Option<Self> {
+        let (L, U, P) = self.lu();
+        if (0..D).any(|i| U[(i, i)].abs() <= eps) {
+            return None;
+        }
+        Self::invert_lu(L, U, P)
+    }
+
+    fn invert_lu(mut L: Self, mut U: Self, P: Self) -> Option<Self> {
         if let (Some(L_inv), Some(U_inv)) = (
             Self::invert_lower_triangular(&mut L),
             Self::invert_upper_triangular(&mut U),
@@ -40,17 +88,56 @@ where
     }
 
     pub fn det(&self) -> T {
-        let (L, U, _) = self.lu();
-        let mut det = T::one();
-        for i in 0..D {
-            det = det * L[(i, i)] * U[(i, i)];
+        let (_, U, P) = self.lu();
+        // A zero pivot means the matrix is singular
+        if (0..D).any(|i| U[(i, i)] == T::zero()) {
+            return T::zero();
         }
-        if D % 2 != 0 {
-            det = -det;
+        // `L` has a unit diagonal, and each row swap recorded in `P` flips the
+        // sign
+        let mut det = Self::permutation_sign(&P);
+        for i in 0..D {
+            det = det * U[(i, i)];
         }
         det
     }
 
+    /// Compute the sign and natural logarithm of the absolute value of the
+    /// determinant.
+    ///
+    /// Returns `(sign, log|det|)` where `sign` is `1`, `-1`, or `0` for a
+    /// singular matrix (in which case `log|det|` is `-∞`). Summing logarithms
+    /// of the LU pivots avoids the overflow and underflow of the plain
+    /// product of diagonal elements.
+    pub fn slogdet(&self) -> (T, T)
+    where
+        T: Ln,
+    {
+        let (_, U, P) = self.lu();
+        let mut sign = Self::permutation_sign(&P);
+        let mut log = T::zero();
+        for i in 0..D {
+            let u = U[(i, i)];
+            if u < T::zero() {
+                sign = -sign;
+            } else if u == T::zero() {
+                sign = T::zero();
+            }
+            log = log + u.abs().ln();
+        }
+        (sign, log)
+    }
+
+    /// Compute the natural logarithm of the absolute value of the determinant.
+    ///
+    /// See [`slogdet`][Self::slogdet] for the accompanying sign.
+    pub fn log_abs_det(&self) -> T
+    where
+        T: Ln,
+    {
+        self.slogdet().1
+    }
+
     pub fn lu(&self) -> (Matrix<D, D, T>, Matrix<D, D, T>, Matrix<D, D, T>) {
         let mut P = eye!(D, T);
         let mut L = eye!(D, T);
@@ -67,6 +154,97 @@ where
         (L, U, P)
     }
 
+    /// Compute the trace of the inverse, `tr(A⁻¹)`, without forming `A⁻¹`.
+    ///
+    /// The matrix is factored once and `A xᵢ = eᵢ` is solved for each basis
+    /// vector, keeping only the `i`-th component of each solution. Returns
+    /// `None` if the matrix is singular.
+    pub fn trace_inverse(&self) -> Option<T> {
+        let (L, U, P) = self.lu();
+        let mut t = T::zero();
+        for i in 0..D {
+            // P * eᵢ is the i-th column of P
+            let mut e = Matrix::<D, 1, T>::zeros();
+            for r in 0..D {
+                e[r] = P[(r, i)];
+            }
+            let x = Self::lu_substitute(&L, &U, &e)?;
+            t = t + x[i];
+        }
+        Some(t)
+    }
+
+    /// Solve the Sylvester equation `A X + X B = C` for `X`.
+    ///
+    /// The equation is vectorized to `(I ⊗ A + Bᵀ ⊗ I) vec(X) = vec(C)` and
+    /// solved with [`solve`][Self::solve]. The size of that system, `L`, must
+    /// equal `D * P`, which is checked at compile time. Returns `None` if `A`
+    /// and `-B` share an eigenvalue, in which case the solution is not unique.
+    pub fn solve_sylvester<const P: usize, const L: usize>(
+        a: &Self,
+        b: &Matrix<P, P, T>,
+        c: &Matrix<D, P, T>,
+    ) -> Option<Matrix<D, P, T>> {
+        const { assert!(L == D * P, "system size must be L = D * P") };
+        let K = a.kron_with_identity_left::<P, L, L>()
+            + b.transpose().kron_with_identity_right::<D, L, L>();
+        let x = K.solve(&c.vec::<L>())?;
+        Some(Matrix::unvec(&x))
+    }
+
+    /// Solve the continuous Lyapunov equation `A X + X Aᵀ + Q = 0` for `X`.
+    ///
+    /// This is the Sylvester equation with `B = Aᵀ` and `C = -Q`, see
+    /// [`solve_sylvester`][Self::solve_sylvester]. `L` must equal `D * D`.
+    /// Returns `None` if no unique solution exists.
+    pub fn solve_lyapunov<const L: usize>(a: &Self, q: &Self) -> Option<Self> {
+        Self::solve_sylvester::<D, L>(a, &a.transpose(), &-q)
+    }
+
+    /// Solve `A x = b` with iterative refinement.
+    ///
+    /// After the initial LU solve, the residual `r = b - A x` is computed and
+    /// the correction `A d = r` is solved with the same factorization, updating
+    /// `x += d`, for `iters` rounds. Returns `None` if the matrix is singular.
+    pub fn solve_refined(&self, b: &Matrix<D, 1, T>, iters: usize) -> Option<Matrix<D, 1, T>> {
+        let (L, U, P) = self.lu();
+        let mut x = Self::lu_substitute(&L, &U, &(P * b))?;
+        for _ in 0..iters {
+            let r = b - self * x;
+            x = x + Self::lu_substitute(&L, &U, &(P * r))?;
+        }
+        Some(x)
+    }
+
+    /// Solve `L U X = B` by forward substitution with the unit lower-triangular
+    /// `L` followed by back substitution with the upper-triangular `U`.
+    ///
+    /// Returns `None` if `U` has a zero on its diagonal.
+    fn lu_substitute<const P: usize>(
+        L: &Matrix<D, D, T>,
+        U: &Matrix<D, D, T>,
+        b: &Matrix<D, P, T>,
+    ) -> Option<Matrix<D, P, T>> {
+        let mut x = *b;
+        for c in 0..P {
+            for r in 0..D {
+                for k in 0..r {
+                    x[(r, c)] = x[(r, c)] - L[(r, k)] * x[(k, c)];
+                }
+            }
+            for r in (0..D).rev() {
+                if U[(r, r)] == T::zero() {
+                    return None;
+                }
+                for k in (r + 1)..D {
+                    x[(r, c)] = x[(r, c)] - U[(r, k)] * x[(k, c)];
+                }
+                x[(r, c)] = x[(r, c)] / U[(r, r)];
+            }
+        }
+        Some(x)
+    }
+
     fn invert_upper_triangular(U: &mut Matrix<D, D, T>) -> Option<Matrix<D, D, T>> {
         let mut I = eye!(D, T);
         for i in (0..D).rev() {
@@ -116,6 +294,12 @@ where
 
     fn gauss_eliminate(L: &mut Matrix<D, D, T>, U: &mut Matrix<D, D, T>, diag: usize) {
         let d = diag;
+        // After partial pivoting a zero pivot means the rest of the column is
+        // zero too, so there is nothing to eliminate. Dividing by it would fill
+        // `L` and `U` with NaN and hide the zero pivot from `inv` and `det`.
+        if U[(d, d)] == T::zero() {
+            return;
+        }
         for r in (d + 1)..D {
             L[(r, d)] = U[(r, d)] / U[(d, d)];
             for c in 0..D {
@@ -141,6 +325,29 @@ where
         }
     }
 
+    /// Sign of the permutation represented by the permutation matrix `P`.
+    fn permutation_sign(P: &Matrix<D, D, T>) -> T {
+        let mut visited = [false; D];
+        let mut sign = T::one();
+        for start in 0..D {
+            if visited[start] {
+                continue;
+            }
+            // Every cycle of length `n` contributes `n - 1` transpositions
+            let mut i = start;
+            let mut len = 0;
+            while !visited[i] {
+                visited[i] = true;
+                len += 1;
+                i = (0..D).find(|&j| P[(i, j)] != T::zero()).unwrap_or(i);
+            }
+            if len % 2 == 0 {
+                sign = -sign;
+            }
+        }
+        sign
+    }
+
     fn find_max_row(U: &Matrix<D, D, T>, diag: usize) -> usize {
         let mut max_row = diag;
         for r in diag..D {
@@ -152,12 +359,334 @@ where
     }
 }
 
+impl<const D: usize, T> Matrix<D, D, T>
+where
+    T: Abs
+        + Sqrt
+        + Epsilon
+        + FromUsize
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Sum
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Compute the principal square root `X` with `X * X == A` using the
+    /// Denman-Beavers iteration.
+    ///
+    /// Intended for symmetric positive-definite matrices, but converges for
+    /// any matrix without eigenvalues on the closed negative real axis.
+    /// The iteration stops once the relative update is at the level of
+    /// round-off, or once it is below `sqrt(eps)` and stops shrinking, which
+    /// happens when round-off keeps it from reaching `eps`. Returns `None` if
+    /// an iterate becomes singular or the iteration does not converge.
+    pub fn sqrtm(&self) -> Option<Self> {
+        let half = T::one() / (T::one() + T::one());
+        let tol = T::epsilon() * T::from_usize(D);
+        let stall_tol = T::epsilon().sqrt();
+        let mut Y = *self;
+        let mut Z = eye!(D, T);
+        let mut prev_delta = None;
+        for _ in 0..SQRTM_MAX_ITERATIONS {
+            let Y_next = (Y + Z.inv()?) * half;
+            let Z_next = (Z + Y.inv()?) * half;
+            let delta = (Y_next - Y).norm();
+            Y = Y_next;
+            Z = Z_next;
+            let scale = Y.norm();
+            if delta <= tol * scale {
+                return Some(Y);
+            }
+            if delta <= stall_tol * scale && prev_delta.is_some_and(|p| delta >= p) {
+                return Some(Y);
+            }
+            prev_delta = Some(delta);
+        }
+        None
+    }
+
+    /// Compute the geometric mean `A^½ (A^-½ B A^-½)^½ A^½` of two symmetric
+    /// positive-definite matrices, the midpoint of the geodesic between them
+    /// on the manifold of SPD matrices.
+    ///
+    /// Returns `None` if either matrix square root or inverse fails.
+    pub fn spd_geometric_mean(&self, other: &Self) -> Option<Self> {
+        let A_half = self.sqrtm()?;
+        let A_neg_half = A_half.inv()?;
+        let M = (A_neg_half * other * A_neg_half).sqrtm()?;
+        Some(A_half * M * A_half)
+    }
+}
+
+impl<T> Matrix<3, 3, T>
+where
+    T: Abs
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Sum
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Compute the three principal invariants `(I₁, I₂, I₃)` of a 3-by-3
+    /// tensor.
+    ///
+    /// These are `I₁ = tr(A)`, `I₂ = (tr(A)² - tr(A²)) / 2` and
+    /// `I₃ = det(A)`, the coefficients of the characteristic polynomial
+    /// `λ³ - I₁λ² + I₂λ - I₃`. They are unchanged by a rotation `R A Rᵀ`.
+    pub fn invariants(&self) -> (T, T, T) {
+        let tr = self.trace();
+        let tr_sq = (self * self).trace();
+        let I2 = (tr * tr - tr_sq) / (T::one() + T::one());
+        (tr, I2, self.det())
+    }
+}
+
+impl<const M: usize, const N: usize, T> Matrix<M, N, T>
+where
+    T: Abs
+        + Sqrt
+        + Epsilon
+        + FromUsize
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Solve the linear least-squares problem `min ||Ax - b||`.
+    ///
+    /// The minimum-norm solution is returned, so rank-deficient systems are
+    /// handled as well. Singular values below `max(M, N) * ε * σ_max` are
+    /// treated as zero.
+    pub fn lstsq(&self, b: &Vector<M, T>) -> Vector<N, T> {
+        let (W, sigma, V) = self.jacobi_svd();
+        let tol = Self::rank_tolerance(&sigma);
+
+        // x = V * Σ⁺ * Uᵀ * b, where the columns of `W` are `σ_j * u_j`
+        let mut x = Vector::<N, T>::zeros();
+        for (j, &s) in sigma.iter().enumerate() {
+            if s <= tol {
+                continue;
+            }
+            let mut wb = T::zero();
+            for i in 0..M {
+                wb = wb + W[(i, j)] * b[i];
+            }
+            let coeff = wb / (s * s);
+            for i in 0..N {
+                x[i] = x[i] + coeff * V[(i, j)];
+            }
+        }
+        x
+    }
+
+    /// Compute a basis for the null space of the matrix.
+    ///
+    /// Returns the nullity `k` and an orthogonal matrix whose first `k`
+    /// columns span the null space. Directions with a singular value of at
+    /// most `eps` are considered to be in the null space. The remaining
+    /// columns span the row space.
+    pub fn null_space(&self, eps: T) -> (usize, Matrix<N, N, T>) {
+        let (_, sigma, V) = self.jacobi_svd();
+        let mut basis = Matrix::<N, N, T>::zeros();
+        let mut nullity = 0;
+        for (j, &s) in sigma.iter().enumerate() {
+            if s <= eps {
+                for i in 0..N {
+                    basis[(i, nullity)] = V[(i, j)];
+                }
+                nullity += 1;
+            }
+        }
+        let mut c = nullity;
+        for (j, &s) in sigma.iter().enumerate() {
+            if s > eps {
+                for i in 0..N {
+                    basis[(i, c)] = V[(i, j)];
+                }
+                c += 1;
+            }
+        }
+        (nullity, basis)
+    }
+
+    /// Compute an orthonormal basis for the column space (range) of the matrix.
+    ///
+    /// Returns the rank `r` and a matrix whose first `r` columns are
+    /// orthonormal and span the column space. Directions with a singular value
+    /// of at most `eps` are discarded and the remaining columns are zero.
+    pub fn range_basis(&self, eps: T) -> (usize, Matrix<M, N, T>) {
+        let (W, sigma, _) = self.jacobi_svd();
+        let mut basis = Matrix::<M, N, T>::zeros();
+        let mut rank = 0;
+        for (j, &s) in sigma.iter().enumerate() {
+            if s > eps {
+                for i in 0..M {
+                    basis[(i, rank)] = W[(i, j)] / s;
+                }
+                rank += 1;
+            }
+        }
+        (rank, basis)
+    }
+
+    /// Orthogonal projector onto the column space of the matrix.
+    ///
+    /// This is `A (AᵀA)⁻¹ Aᵀ` for full column rank, but is computed from the
+    /// singular value decomposition so rank-deficient matrices are handled as
+    /// well (it equals `A A⁺`).
+    pub fn projector(&self) -> Matrix<M, M, T> {
+        let (W, sigma, _) = self.jacobi_svd();
+        let tol = Self::rank_tolerance(&sigma);
+        let mut P = Matrix::<M, M, T>::zeros();
+        for (k, &s) in sigma.iter().enumerate() {
+            if s <= tol {
+                continue;
+            }
+            let s2 = s * s;
+            for j in 0..M {
+                for i in 0..M {
+                    P[(i, j)] = P[(i, j)] + W[(i, k)] * W[(j, k)] / s2;
+                }
+            }
+        }
+        P
+    }
+
+    /// Default threshold below which singular values are treated as zero.
+    fn rank_tolerance(sigma: &[T; N]) -> T {
+        let mut sigma_max = T::zero();
+        for &s in sigma {
+            if s > sigma_max {
+                sigma_max = s;
+            }
+        }
+        sigma_max * T::epsilon() * T::from_usize(M.max(N))
+    }
+
+    /// Solve the weighted least-squares problem `min Σ wᵢ (Aᵢx - bᵢ)²`.
+    ///
+    /// Weights are expected to be non-negative. Each row of `A` and entry of
+    /// `b` is scaled by `sqrt(wᵢ)` before calling [`lstsq`][Self::lstsq].
+    pub fn lstsq_weighted(&self, b: &Vector<M, T>, w: &Vector<M, T>) -> Vector<N, T> {
+        let mut A = *self;
+        let mut b = *b;
+        for i in 0..M {
+            let sw = w[i].sqrt();
+            for j in 0..N {
+                A[(i, j)] = A[(i, j)] * sw;
+            }
+            b[i] = b[i] * sw;
+        }
+        A.lstsq(&b)
+    }
+}
+
+impl<const M: usize, const N: usize, T> Matrix<M, N, T>
+where
+    T: Abs
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Sum
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Compute the Moore-Penrose pseudo-inverse of a full-rank matrix.
+    ///
+    /// For tall matrices (`M >= N`) this is the left inverse `(AᵀA)⁻¹Aᵀ`, so
+    /// `pinv(A) * b` is the least-squares solution of `A x = b`. For wide
+    /// matrices it is the right inverse `Aᵀ(AAᵀ)⁻¹`, giving the minimum-norm
+    /// solution. Returns `None` if the matrix does not have full rank, i.e. the
+    /// relevant Gram matrix is singular.
+    ///
+    /// Forming the Gram matrix squares the condition number; prefer
+    /// [`lstsq`][Self::lstsq] for ill-conditioned problems.
+    pub fn pinv(&self) -> Option<Matrix<N, M, T>> {
+        let At = self.transpose();
+        if M >= N {
+            Some((At * self).inv()? * At)
+        } else {
+            Some(At * (self * At).inv()?)
+        }
+    }
+}
+
+impl<const M: usize, T> Vector<M, T>
+where
+    T: Abs
+        + Sqrt
+        + Epsilon
+        + FromUsize
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Fit a polynomial with `K` coefficients (degree `K - 1`) to the samples
+    /// `(x, y)` in the least-squares sense.
+    ///
+    /// The coefficients are returned in ascending order of power, i.e.
+    /// `y ≈ c[0] + c[1] x + ... + c[K-1] x^(K-1)`, matching the columns of
+    /// [`vandermonde`][Matrix::vandermonde]. Evaluate the result with
+    /// [`polyval`][Self::polyval].
+    pub fn polyfit<const K: usize>(x: &Vector<M, T>, y: &Vector<M, T>) -> Vector<K, T> {
+        Matrix::<M, K, T>::vandermonde(x).lstsq(y)
+    }
+}
+
+impl<const N: usize, T> Vector<N, T>
+where
+    T: Sqrt + Copy + Zero + Sum + Mul<Output = T> + Sub<Output = T>,
+{
+    /// Compute the Mahalanobis distance `sqrt((x - y)ᵀ Σ⁻¹ (x - y))` between
+    /// `self` and `other`, given the inverse covariance matrix `Σ⁻¹`.
+    pub fn mahalanobis(&self, other: &Vector<N, T>, cov_inv: &Matrix<N, N, T>) -> T {
+        let d = self - other;
+        (d.T() * cov_inv * d)[0].sqrt()
+    }
+}
+
+impl<const K: usize, T> Vector<K, T>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    /// Evaluate the polynomial with coefficients `self` (in ascending order of
+    /// power) at `x` using Horner's method.
+    pub fn polyval(&self, x: T) -> T {
+        (0..K).rev().fold(T::zero(), |acc, i| acc * x + self[i])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::{assert_abs_diff_eq, assert_relative_eq};
 
     use super::*;
-    use crate::matrix;
+    use crate::{matrix, vector};
 
     #[test]
     fn LU_decomp() {
@@ -236,7 +765,7 @@ mod tests {
             3.0, 17.0, 18.0, 1.0;
             2.0, 5.0, 7.0, 1.0;
         ];
-        assert_abs_diff_eq!(A.det(), -284.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(A.det(), 284.0, epsilon = 1e-10);
 
         let A = matrix![
               2.0, 3.0, 0.0, 9.0, 0.0, 1.0, 0.0, 1.0, 1.0, 2.0, 1.0;
@@ -254,6 +783,85 @@ mod tests {
         assert_abs_diff_eq!(A.det(), 0.0, epsilon = 1e-10);
     }
 
+    #[test]
+    fn invariants() {
+        let A = matrix![
+            2.0, 1.0, 0.0;
+            1.0, 3.0, 0.0;
+            0.0, 0.0, 4.0;
+        ];
+        let (I1, I2, I3) = A.invariants();
+        assert_abs_diff_eq!(I1, 9.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(I2, 25.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(I3, 20.0, epsilon = 1e-12);
+
+        // Unchanged by a rotation about z
+        let R = matrix![
+            0.6, -0.8, 0.0;
+            0.8,  0.6, 0.0;
+            0.0,  0.0, 1.0;
+        ];
+        let (J1, J2, J3) = (R * A * R.T()).invariants();
+        assert_abs_diff_eq!(J1, I1, epsilon = 1e-12);
+        assert_abs_diff_eq!(J2, I2, epsilon = 1e-12);
+        assert_abs_diff_eq!(J3, I3, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn determinant_pivot_sign() {
+        // No row swaps
+        let A = matrix![
+            4.0, 1.0, 0.0;
+            1.0, 3.0, 1.0;
+            0.0, 1.0, 2.0;
+        ];
+        assert_abs_diff_eq!(A.det(), 18.0, epsilon = 1e-10);
+
+        // One row swap
+        let A = matrix![
+            0.0, 1.0, 0.0;
+            1.0, 0.0, 0.0;
+            0.0, 0.0, 2.0;
+        ];
+        assert_abs_diff_eq!(A.det(), -2.0, epsilon = 1e-10);
+
+        // Two row swaps
+        let A = matrix![
+            0.0, 0.0, 1.0;
+            1.0, 0.0, 0.0;
+            0.0, 1.0, 0.0;
+        ];
+        assert_abs_diff_eq!(A.det(), 1.0, epsilon = 1e-10);
+        let A = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 10.0;
+        ];
+        assert_abs_diff_eq!(A.det(), -3.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn determinant_singular() {
+        // Zero column: previously produced NaN through a 0 / 0 pivot
+        let A = matrix![
+            0.0, 1.0, 2.0;
+            0.0, 3.0, 4.0;
+            0.0, 5.0, 7.0;
+        ];
+        assert_eq!(A.det(), 0.0);
+
+        // Dependent rows give an exactly-zero pivot, and the sign of the
+        // product of pivots could otherwise come out as -0.0
+        let A = matrix![
+            1.0, 2.0, 3.0;
+            2.0, 4.0, 6.0;
+            1.0, 0.0, 1.0;
+        ];
+        let det: f64 = A.det();
+        assert_eq!(det, 0.0);
+        assert!(det.is_sign_positive());
+    }
+
     #[test]
     fn upper_inverse() {
         let mut A = matrix![
@@ -314,4 +922,562 @@ mod tests {
         ];
         assert_relative_eq!(A.inv().unwrap(), exp, max_relative = 1e-6);
     }
+
+    #[test]
+    fn inverse_singular() {
+        // A zero column gives an exactly-zero pivot
+        let A = matrix![
+            0.0, 1.0, 2.0;
+            0.0, 3.0, 4.0;
+            0.0, 5.0, 7.0;
+        ];
+        let (L, U, _) = A.lu();
+        assert!(L.iter().chain(U.iter()).all(|x: &f64| !x.is_nan()));
+        assert_eq!(U[(0, 0)], 0.0);
+        assert_eq!(A.inv(), None);
+
+        let A = matrix![
+            1.0, 2.0, 3.0;
+            2.0, 4.0, 6.0;
+            1.0, 0.0, 1.0;
+        ];
+        assert_eq!(A.inv(), None);
+    }
+
+    #[test]
+    fn try_div() {
+        let A = matrix![
+            1.0, 2.0;
+            3.0, 4.0;
+        ];
+        let B = matrix![
+            2.0, 1.0;
+            1.0, 1.0;
+        ];
+        let X = A.try_div(&B).unwrap();
+        assert_relative_eq!(X * B, A, max_relative = 1e-12);
+        assert_relative_eq!(A / B, X, max_relative = 1e-12);
+
+        let S = matrix![
+            1.0, 2.0;
+            2.0, 4.0;
+        ];
+        assert_eq!(A.try_div(&S), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_singular() {
+        let A = matrix![
+            1.0, 2.0;
+            3.0, 4.0;
+        ];
+        let S = matrix![
+            1.0, 2.0;
+            2.0, 4.0;
+        ];
+        let _ = A / S;
+    }
+
+    #[test]
+    fn solve() {
+        let A = matrix![
+            2.0, 1.0, -1.0;
+            -3.0, -1.0, 2.0;
+            -2.0, 1.0, 2.0;
+        ];
+        let b = vector![8.0; -11.0; -3.0];
+        let x = A.solve(&b).unwrap();
+        assert_relative_eq!(x, vector![2.0; 3.0; -1.0], max_relative = 1e-12);
+
+        // Multiple right-hand sides
+        let B = matrix![
+            8.0, 1.0;
+            -11.0, 0.0;
+            -3.0, 0.0;
+        ];
+        let X = A.solve(&B).unwrap();
+        assert_relative_eq!(A * X, B, epsilon = 1e-12);
+
+        let S = matrix![
+            1.0, 2.0, 3.0;
+            2.0, 4.0, 6.0;
+            1.0, 0.0, 1.0;
+        ];
+        assert_eq!(S.solve(&b), None);
+    }
+
+    #[test]
+    fn solve_sylvester() {
+        let A = matrix![
+            1.0, 2.0;
+            0.0, 3.0;
+        ];
+        let B = matrix![
+            4.0, 0.0, 1.0;
+            1.0, 5.0, 0.0;
+            0.0, 1.0, 2.0;
+        ];
+        let X = matrix![
+            1.0, 0.0, -2.0;
+            2.0, -1.0, 0.5;
+        ];
+        let C = A * X + X * B;
+        let S = Matrix::solve_sylvester::<3, 6>(&A, &B, &C).unwrap();
+        assert_relative_eq!(S, X, max_relative = 1e-12);
+
+        // A and -B share the eigenvalue 1
+        let B = matrix![
+            -1.0, 0.0;
+            0.0, 2.0;
+        ];
+        let C = matrix![
+            1.0, 0.0;
+            0.0, 1.0;
+        ];
+        assert_eq!(Matrix::solve_sylvester::<2, 4>(&A, &B, &C), None);
+    }
+
+    #[test]
+    fn solve_lyapunov() {
+        // Stable system with eigenvalues -1 and -2
+        let A = matrix![
+            0.0, 1.0;
+            -2.0, -3.0;
+        ];
+        let Q = eye!(2, f64);
+        let X = Matrix::solve_lyapunov::<4>(&A, &Q).unwrap();
+        let E = matrix![
+            1.0, -0.5;
+            -0.5, 0.5;
+        ];
+        assert_relative_eq!(X, E, max_relative = 1e-12);
+        assert_abs_diff_eq!(A * X + X * A.T() + Q, Matrix::zeros(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn left_divide() {
+        let A = matrix![
+            11.0, 9.0, 24.0, 2.0;
+            1.0, 5.0, 2.0, 6.0;
+            3.0, 17.0, 18.0, 1.0;
+            2.0, 5.0, 7.0, 1.0;
+        ];
+        let B = matrix![
+            1.0, 0.0;
+            2.0, 1.0;
+            -1.0, 3.0;
+            0.5, 2.0;
+        ];
+        let X = A.left_divide(&B).unwrap();
+        assert_relative_eq!(X, A.inv().unwrap() * B, max_relative = 1e-10);
+        assert_relative_eq!(A * X, B, max_relative = 1e-10);
+
+        let S = matrix![
+            1.0, 2.0;
+            2.0, 4.0;
+        ];
+        assert_eq!(S.left_divide(&vector![1.0; 2.0]), None);
+    }
+
+    #[test]
+    fn inv_tol() {
+        let A = matrix![
+            1.0, 1.0;
+            1.0, 1.0 + 1e-15;
+        ];
+        // The second pivot is tiny but nonzero
+        assert!(A.inv().is_some());
+        assert_eq!(A.inv_tol(1e-12), None);
+        assert!(A.inv_tol(0.0).is_some());
+
+        let A = matrix![
+            6.0, 2.0, 3.0;
+            1.0, 1.0, 1.0;
+            0.0, 4.0, 9.0;
+        ];
+        assert_eq!(A.inv_tol(1e-12), A.inv());
+    }
+
+    #[test]
+    fn lstsq() {
+        // Consistent overdetermined system
+        let A = matrix![
+            1.0, 0.0;
+            0.0, 1.0;
+            1.0, 1.0;
+        ];
+        let b = vector![1.0; 2.0; 3.0];
+        assert_relative_eq!(A.lstsq(&b), vector![1.0; 2.0], max_relative = 1e-12);
+
+        // Inconsistent system (line fit through 4 points)
+        let A = matrix![
+            1.0, 0.0;
+            1.0, 1.0;
+            1.0, 2.0;
+            1.0, 3.0;
+        ];
+        let b = vector![1.0; 2.0; 2.0; 4.0];
+        assert_relative_eq!(A.lstsq(&b), vector![0.9; 0.9], max_relative = 1e-12);
+
+        // Rank-deficient system returns the minimum-norm solution
+        let A = matrix![
+            1.0, 1.0;
+            1.0, 1.0;
+            1.0, 1.0;
+        ];
+        let b = vector![1.0; 2.0; 3.0];
+        assert_relative_eq!(A.lstsq(&b), vector![1.0; 1.0], max_relative = 1e-12);
+    }
+
+    #[test]
+    fn pinv() {
+        // Fit y = c0 + c1 x to (0, 1), (1, 3), (2, 4), (3, 7)
+        let A = matrix![
+            1.0, 0.0;
+            1.0, 1.0;
+            1.0, 2.0;
+            1.0, 3.0;
+        ];
+        let b = vector![1.0; 3.0; 4.0; 7.0];
+        let x = A.pinv().unwrap() * b;
+        assert_relative_eq!(x, vector![0.9; 1.9], epsilon = 1e-12);
+        assert_relative_eq!(x, A.lstsq(&b), epsilon = 1e-12);
+
+        // Right inverse of a wide matrix
+        let B = A.T();
+        assert_relative_eq!(B * B.pinv().unwrap(), eye!(2, f64), epsilon = 1e-12);
+
+        // Rank deficient
+        let A = matrix![
+            1.0, 2.0;
+            2.0, 4.0;
+            3.0, 6.0;
+        ];
+        assert_eq!(A.pinv(), None);
+    }
+
+    #[test]
+    fn lstsq_weighted() {
+        let A = matrix![
+            1.0;
+            1.0;
+            1.0;
+        ];
+        let b = vector![1.0; 2.0; 6.0];
+        assert_relative_eq!(A.lstsq(&b), vector![3.0], max_relative = 1e-12);
+
+        let w = vector![1.0; 1.0; 2.0];
+        assert_relative_eq!(
+            A.lstsq_weighted(&b, &w),
+            vector![3.75],
+            max_relative = 1e-12
+        );
+
+        // Zero weight drops the third observation entirely
+        let A = matrix![
+            1.0, 0.0;
+            1.0, 1.0;
+            1.0, 2.0;
+        ];
+        let b = vector![1.0; 3.0; 0.0];
+        let w = vector![1.0; 1.0; 0.0];
+        assert_relative_eq!(
+            A.lstsq_weighted(&b, &w),
+            vector![1.0; 2.0],
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn null_space() {
+        let A = matrix![
+            1.0, 2.0, 3.0;
+            2.0, 4.0, 6.0;
+            1.0, 0.0, 1.0;
+        ];
+        let (nullity, basis) = A.null_space(1e-9);
+        assert_eq!(nullity, 1);
+        let n = Vector::from_column_major_order([[basis[(0, 0)], basis[(1, 0)], basis[(2, 0)]]]);
+        assert_abs_diff_eq!(A * n, vector![0.0; 0.0; 0.0], epsilon = 1e-12);
+
+        // Known direction (1, 1, -1) up to sign
+        let k = 1.0 / libm::sqrt(3.0);
+        let d = n[0] * k + n[1] * k - n[2] * k;
+        assert_relative_eq!(d.abs(), 1.0, max_relative = 1e-12);
+
+        // Full-rank matrices have a trivial null space
+        let (nullity, _) = eye!(3, f64).null_space(1e-9);
+        assert_eq!(nullity, 0);
+
+        // Wide matrices always have a non-trivial null space
+        let A = matrix![
+            1.0, 0.0, 1.0;
+            0.0, 1.0, 1.0;
+        ];
+        let (nullity, basis) = A.null_space(1e-9);
+        assert_eq!(nullity, 1);
+        let n = Vector::from_column_major_order([[basis[(0, 0)], basis[(1, 0)], basis[(2, 0)]]]);
+        assert_abs_diff_eq!(A * n, vector![0.0; 0.0], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn range_basis() {
+        // Second column is twice the first
+        let A = matrix![
+            1.0, 2.0, 0.0;
+            2.0, 4.0, 1.0;
+            3.0, 6.0, 0.0;
+            0.0, 0.0, 1.0;
+        ];
+        let (rank, U) = A.range_basis(1e-9);
+        assert_eq!(rank, 2);
+        let G = U.T() * U;
+        let exp = matrix![
+            1.0, 0.0, 0.0;
+            0.0, 1.0, 0.0;
+            0.0, 0.0, 0.0;
+        ];
+        assert_abs_diff_eq!(G, exp, epsilon = 1e-12);
+        // Every column of `A` lies in the span of the basis
+        assert_relative_eq!(U * (U.T() * A), A, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn projector() {
+        let A = matrix![
+            1.0, 0.0;
+            1.0, 1.0;
+            1.0, 2.0;
+        ];
+        let P = A.projector();
+        assert_relative_eq!(P * P, P, epsilon = 1e-12);
+        assert_relative_eq!(P, P.T(), epsilon = 1e-12);
+        assert_relative_eq!(P * A, A, epsilon = 1e-12);
+        assert_relative_eq!(P.trace(), 2.0, epsilon = 1e-12);
+
+        // Rank-deficient matrix projects onto its one-dimensional range
+        let A = matrix![
+            1.0, 2.0;
+            2.0, 4.0;
+            2.0, 4.0;
+        ];
+        let P = A.projector();
+        assert_relative_eq!(P * P, P, epsilon = 1e-12);
+        assert_relative_eq!(P.trace(), 1.0, epsilon = 1e-12);
+        let exp = matrix![
+            1.0, 2.0, 2.0;
+            2.0, 4.0, 4.0;
+            2.0, 4.0, 4.0;
+        ] / 9.0;
+        assert_relative_eq!(P, exp, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn trace_inverse() {
+        let A = matrix![
+            11.0, 9.0, 24.0, 2.0;
+            1.0, 5.0, 2.0, 6.0;
+            3.0, 17.0, 18.0, 1.0;
+            2.0, 5.0, 7.0, 1.0;
+        ];
+        let exp = A.inv().unwrap().trace();
+        assert_relative_eq!(A.trace_inverse().unwrap(), exp, max_relative = 1e-10);
+
+        let A = matrix![
+            1.0, 2.0;
+            2.0, 4.0;
+        ];
+        assert_eq!(A.trace_inverse(), None);
+    }
+
+    #[test]
+    fn log_abs_det() {
+        let A = matrix![
+            11.0, 9.0, 24.0, 2.0;
+            1.0, 5.0, 2.0, 6.0;
+            3.0, 17.0, 18.0, 1.0;
+            2.0, 5.0, 7.0, 1.0;
+        ];
+        assert_relative_eq!(
+            libm::exp(A.log_abs_det()),
+            A.det().abs(),
+            max_relative = 1e-10
+        );
+        assert_eq!(A.slogdet().0, 1.0);
+
+        let A = matrix![
+            6.0, 2.0, 3.0;
+            1.0, 1.0, 1.0;
+            0.0, 4.0, 9.0;
+        ];
+        let (sign, log) = A.slogdet();
+        assert_eq!(sign, 1.0);
+        assert_relative_eq!(log, libm::log(24.0), max_relative = 1e-12);
+
+        let A = matrix![
+            0.0, 1.0;
+            1.0, 0.0;
+        ];
+        assert_eq!(A.slogdet(), (-1.0, 0.0));
+
+        // Determinant far outside the range of `f64`
+        let A = eye!(100, f64) * 1e10;
+        assert_eq!(A.det(), f64::INFINITY);
+        assert_relative_eq!(
+            A.log_abs_det(),
+            1000.0 * libm::log(10.0),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn solve_refined() {
+        // Wilkinson's matrix has maximal element growth under partial
+        // pivoting, so the plain single-precision solve is inaccurate
+        const D: usize = 24;
+        let mut A = eye!(D, f32);
+        let mut x = Matrix::<D, 1, f32>::zeros();
+        for r in 0..D {
+            for c in 0..r {
+                A[(r, c)] = -1.0;
+            }
+            A[(r, D - 1)] = 1.0;
+            x[r] = 1.0 / (r as f32 + 3.0);
+        }
+        let b = A * x;
+
+        let plain = A.solve_refined(&b, 0).unwrap();
+        let refined = A.solve_refined(&b, 2).unwrap();
+        let plain_residual = (b - A * plain).norm();
+        let refined_residual = (b - A * refined).norm();
+        assert!(refined_residual < 1e-3 * plain_residual);
+        assert_relative_eq!(refined, x, epsilon = 1e-5);
+
+        let A = matrix![
+            1.0, 2.0;
+            2.0, 4.0;
+        ];
+        assert_eq!(A.solve_refined(&vector![1.0; 2.0], 2), None);
+    }
+
+    #[test]
+    fn polyfit() {
+        // Samples of y = 2 - 3x + 0.5x²
+        let x = vector![-2.0; -1.0; 0.0; 1.0; 2.0; 3.0];
+        let mut y = x;
+        for i in 0..6 {
+            y[i] = 2.0 - 3.0 * x[i] + 0.5 * x[i] * x[i];
+        }
+        let c = Vector::polyfit::<3>(&x, &y);
+        assert_relative_eq!(c, vector![2.0; -3.0; 0.5], epsilon = 1e-12);
+        assert_relative_eq!(c.polyval(4.0), 2.0 - 12.0 + 8.0, epsilon = 1e-12);
+
+        // A line through the same points fits the best slope and intercept
+        let c = Vector::polyfit::<2>(&x, &y);
+        assert_relative_eq!(c, vector![10.0 / 3.0; -2.5], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn polyval() {
+        let c = vector![1.0; 0.0; -2.0; 1.0];
+        assert_eq!(c.polyval(0.0), 1.0);
+        assert_eq!(c.polyval(2.0), 1.0 - 8.0 + 8.0);
+        assert_eq!(c.polyval(-1.0), 1.0 - 2.0 - 1.0);
+    }
+
+    #[test]
+    fn sqrtm() {
+        let A = matrix![
+            4.0, 12.0, -16.0;
+            12.0, 37.0, -43.0;
+            -16.0, -43.0, 98.0;
+        ];
+        let X = A.sqrtm().unwrap();
+        assert_relative_eq!(X * X, A, max_relative = 1e-10);
+        // The principal root of an SPD matrix is symmetric
+        assert_relative_eq!(X, X.T(), max_relative = 1e-10);
+
+        let A = matrix![
+            9.0, 0.0;
+            0.0, 0.25;
+        ];
+        assert_relative_eq!(
+            A.sqrtm().unwrap(),
+            matrix![3.0, 0.0; 0.0, 0.5],
+            epsilon = 1e-12
+        );
+
+        // Singular matrices have no invertible iterates
+        let A = matrix![
+            1.0, 1.0;
+            1.0, 1.0;
+        ];
+        assert_eq!(A.sqrtm(), None);
+    }
+
+    #[test]
+    fn spd_geometric_mean() {
+        let A = matrix![
+            4.0, 1.0, 0.5;
+            1.0, 3.0, 0.2;
+            0.5, 0.2, 2.0;
+        ];
+        assert_relative_eq!(A.spd_geometric_mean(&A).unwrap(), A, max_relative = 1e-10);
+
+        // Commuting matrices reduce to the elementwise geometric mean
+        let A = matrix![
+            1.0, 0.0;
+            0.0, 9.0;
+        ];
+        let B = matrix![
+            4.0, 0.0;
+            0.0, 1.0;
+        ];
+        let G = A.spd_geometric_mean(&B).unwrap();
+        assert_relative_eq!(G, matrix![2.0, 0.0; 0.0, 3.0], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn sqrtm_f32() {
+        let A = matrix![
+            4.0_f32, 12.0, -16.0;
+            12.0, 37.0, -43.0;
+            -16.0, -43.0, 98.0;
+        ];
+        let X = A.sqrtm().unwrap();
+        assert_relative_eq!(X * X, A, max_relative = 1e-4);
+        assert_relative_eq!(X, X.T(), max_relative = 1e-4);
+    }
+
+    #[test]
+    fn sqrtm_ill_conditioned() {
+        // SPD with a condition number of about 1e8
+        let Q = Matrix::<3, 3, f64>::from_axis_angle(&(vector![1.0; 2.0; 2.0] / 3.0), 0.7);
+        let A = Q * matrix![1e4, 0.0, 0.0; 0.0, 1.0, 0.0; 0.0, 0.0, 1e-4] * Q.T();
+        let X = A.sqrtm().unwrap();
+        assert_relative_eq!(X * X, A, epsilon = 1e-9, max_relative = 1e-6);
+        let exp = Q * matrix![1e2, 0.0, 0.0; 0.0, 1.0, 0.0; 0.0, 0.0, 1e-2] * Q.T();
+        assert_relative_eq!(X, exp, epsilon = 1e-9, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn mahalanobis() {
+        let x = vector![1.0; 2.0; 3.0];
+        let y = vector![4.0; 6.0; 3.0];
+        assert_relative_eq!(x.mahalanobis(&y, &eye!(3, f64)), 5.0, max_relative = 1e-12);
+        assert_relative_eq!(x.mahalanobis(&y, &eye!(3, f64)), (x - y).norm());
+
+        // Scaling by the variances along each axis
+        let cov_inv = matrix![
+            1.0 / 9.0, 0.0, 0.0;
+            0.0, 1.0 / 16.0, 0.0;
+            0.0, 0.0, 1.0;
+        ];
+        assert_relative_eq!(
+            x.mahalanobis(&y, &cov_inv),
+            libm::sqrt(2.0),
+            max_relative = 1e-12
+        );
+    }
 }
@@ -0,0 +1,372 @@
+//! A lightweight complex number type.
+//!
+//! [`Complex`] implements the crate's number traits and the arithmetic
+//! operators, so it can be used as the element type of a [`Matrix`] for
+//! arithmetic, [`norm`][Matrix::norm] and [`inv`][Matrix::inv].
+
+use core::{
+    cmp::Ordering,
+    iter::Sum,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::{
+    num::{Abs, Cos, FromUsize, One, Pi, Sin, Sqrt, Zero},
+    Matrix,
+};
+
+/// A complex number in Cartesian form `re + i * im`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Complex<T> {
+    /// Real part.
+    pub re: T,
+    /// Imaginary part.
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    /// Create a new complex number from its real and imaginary parts.
+    #[inline]
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Returns the squared modulus `re² + im²`.
+    #[inline]
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl<T: Neg<Output = T>> Complex<T> {
+    /// Returns the complex conjugate `re - i * im`.
+    #[inline]
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Copy + Sin + Cos + Mul<Output = T>,
+{
+    /// Create the complex number `r * exp(i * theta)`.
+    #[inline]
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+}
+
+impl<T: Zero> Zero for Complex<T> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+}
+
+impl<T: Zero + One> One for Complex<T> {
+    #[inline]
+    fn one() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+}
+
+/// The modulus `|z|`, returned as the complex number `|z| + 0i`.
+///
+/// [`Abs`] returns `Self`, so the magnitude is carried in the real part. As a
+/// consequence [`Matrix::norm`] of a complex matrix is a `Complex` whose real
+/// part is the Frobenius norm `sqrt(Σ |zᵢⱼ|²)` and whose imaginary part is
+/// zero.
+impl<T> Abs for Complex<T>
+where
+    T: Copy + Zero + Sqrt + Add<Output = T> + Mul<Output = T>,
+{
+    #[inline]
+    fn abs(self) -> Self {
+        Self::new(self.norm_sqr().sqrt(), T::zero())
+    }
+}
+
+/// The principal square root, with a non-negative real part.
+impl<T> Sqrt for Complex<T>
+where
+    T: Copy
+        + Zero
+        + One
+        + PartialOrd
+        + Sqrt
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>,
+{
+    fn sqrt(self) -> Self {
+        let two = T::one() + T::one();
+        let r = self.norm_sqr().sqrt();
+        // Rounding or underflow in `r` can push these just below zero close
+        // to the real axis
+        let clamp = |x: T| if x < T::zero() { T::zero() } else { x };
+        let re = clamp((r + self.re) / two).sqrt();
+        let im = clamp((r - self.re) / two).sqrt();
+        if self.im < T::zero() {
+            Self::new(re, -im)
+        } else {
+            Self::new(re, im)
+        }
+    }
+}
+
+/// Complex numbers are only ordered along lines of equal imaginary part.
+///
+/// Values with the same imaginary part are compared by their real parts, any
+/// other pair is unordered. This keeps comparisons of moduli returned by
+/// [`Abs`] (which are all real) meaningful, as needed for pivoting in
+/// [`Matrix::inv`].
+impl<T: PartialOrd> PartialOrd for Complex<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.im == other.im {
+            self.re.partial_cmp(&other.re)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Add for Complex<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T> Sub for Complex<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T> Neg for Complex<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<T> Mul for Complex<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T> Div for Complex<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let d = rhs.norm_sqr();
+        Self::new(
+            (self.re * rhs.re + self.im * rhs.im) / d,
+            (self.im * rhs.re - self.re * rhs.im) / d,
+        )
+    }
+}
+
+impl<T> Sum for Complex<T>
+where
+    T: Zero + Add<Output = T>,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, z| acc + z)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Complex matrix constructors
+////////////////////////////////////////////////////////////////////////////////
+
+impl<const N: usize, T> Matrix<N, N, Complex<T>>
+where
+    T: Copy
+        + Zero
+        + One
+        + FromUsize
+        + Pi
+        + Sin
+        + Cos
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>,
+{
+    /// Create the `N`-point discrete Fourier transform matrix
+    /// `W[(j, k)] = exp(-2πi jk / N)`.
+    ///
+    /// Multiplying a signal by this matrix computes its (unnormalized) DFT in
+    /// `O(N²)` operations.
+    pub fn dft_matrix() -> Self {
+        let mut m = Self::zeros();
+        let n = T::from_usize(N);
+        let two_pi = T::pi() + T::pi();
+        for k in 0..N {
+            for j in 0..N {
+                // Reduce `jk` modulo `N` to keep the angle small
+                let jk = T::from_usize((j * k) % N);
+                m[(j, k)] = Complex::from_polar(T::one(), -(two_pi * jk / n));
+            }
+        }
+        m
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::Vector;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = c(1.0, 2.0);
+        let b = c(3.0, -1.0);
+        assert_eq!(a + b, c(4.0, 1.0));
+        assert_eq!(a - b, c(-2.0, 3.0));
+        assert_eq!(a * b, c(5.0, 5.0));
+        assert_eq!(-a, c(-1.0, -2.0));
+        assert_eq!(a.conj(), c(1.0, -2.0));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(c(3.0, 4.0).abs(), c(5.0, 0.0));
+
+        let r = c(-3.0, -4.0).sqrt();
+        assert_relative_eq!(r.re, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(r.im, -2.0, epsilon = 1e-12);
+        assert_eq!(c(-4.0, 0.0).sqrt(), c(0.0, 2.0));
+
+        assert!(c(1.0, 0.0) < c(2.0, 0.0));
+        assert_eq!(c(1.0, 1.0).partial_cmp(&c(2.0, 0.0)), None);
+    }
+
+    #[test]
+    fn sqrt_near_real_axis() {
+        let r = c(-4.0, 1e-20).sqrt();
+        assert_relative_eq!(r.re, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(r.im, 2.0, epsilon = 1e-12);
+        let r = Complex::new(-4.0_f32, -1e-30).sqrt();
+        assert_relative_eq!(r.re, 0.0, epsilon = 1e-6);
+        assert_relative_eq!(r.im, -2.0, epsilon = 1e-6);
+
+        // The squared modulus underflows to zero, which used to give NaN
+        let r = c(-1e-200, 1e-300).sqrt();
+        assert_eq!(r.re, 0.0);
+        assert!(r.im > 0.0 && r.im.is_finite());
+        let r = c(1e-200, -1e-300).sqrt();
+        assert!(r.re > 0.0 && r.re.is_finite());
+        assert_eq!(r.im, 0.0);
+    }
+
+    #[test]
+    fn norm() {
+        let m = Matrix::from_column_major_order([
+            [c(3.0, 4.0), c(0.0, 1.0)],
+            [c(-1.0, 0.0), c(1.0, -1.0)],
+        ]);
+        // sqrt(25 + 1 + 1 + 2) carried in the real part
+        let n = m.norm();
+        assert_relative_eq!(n.re, 29.0_f64.sqrt(), epsilon = 1e-12);
+        assert_eq!(n.im, 0.0);
+    }
+
+    #[test]
+    fn inv() {
+        let m = Matrix::from_column_major_order([
+            [c(1.0, 1.0), c(0.0, 2.0), c(1.0, 0.0)],
+            [c(2.0, 0.0), c(1.0, -1.0), c(0.0, 0.0)],
+            [c(0.0, -1.0), c(3.0, 0.0), c(1.0, 2.0)],
+        ]);
+        let id = m * m.inv().unwrap();
+        for r in 0..3 {
+            for c in 0..3 {
+                let exp = if r == c { 1.0 } else { 0.0 };
+                assert_relative_eq!(id[(r, c)].re, exp, epsilon = 1e-12);
+                assert_relative_eq!(id[(r, c)].im, 0.0, epsilon = 1e-12);
+            }
+        }
+
+        let singular = Matrix::from_column_major_order([
+            [c(1.0, 1.0), c(2.0, 2.0)],
+            [c(0.0, 1.0), c(0.0, 2.0)],
+        ]);
+        assert_eq!(singular.inv(), None);
+    }
+
+    #[test]
+    fn dft_matrix() {
+        let w = Matrix::<4, 4, Complex<f64>>::dft_matrix();
+        let x = Vector::from_column_major_order([[
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 0.0),
+            Complex::new(3.0, 0.0),
+            Complex::new(4.0, 0.0),
+        ]]);
+        let y = w * x;
+        let exp = [(10.0, 0.0), (-2.0, 2.0), (-2.0, 0.0), (-2.0, -2.0)];
+        for (i, (re, im)) in exp.into_iter().enumerate() {
+            assert_relative_eq!(y[i].re, re, epsilon = 1e-12);
+            assert_relative_eq!(y[i].im, im, epsilon = 1e-12);
+        }
+
+        // Compare against the DFT sum evaluated directly
+        let x: [f64; 5] = [0.5, -1.0, 2.0, 0.25, 3.0];
+        let w = Matrix::<5, 5, Complex<f64>>::dft_matrix();
+        let y = w * Vector::from_column_major_order([x.map(|v| Complex::new(v, 0.0))]);
+        for k in 0..5 {
+            let mut re = 0.0;
+            let mut im = 0.0;
+            for (j, v) in x.iter().enumerate() {
+                let theta = -2.0 * core::f64::consts::PI * (j * k) as f64 / 5.0;
+                re += v * libm::cos(theta);
+                im += v * libm::sin(theta);
+            }
+            assert_relative_eq!(y[k].re, re, epsilon = 1e-12);
+            assert_relative_eq!(y[k].im, im, epsilon = 1e-12);
+        }
+    }
+}
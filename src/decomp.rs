@@ -0,0 +1,1187 @@
+#![allow(non_snake_case)]
+
+//! Matrix decompositions.
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(feature = "complex")]
+use crate::Complex;
+use crate::{
+    eye,
+    num::{Abs, Acos, Atan2, Cos, Epsilon, FromUsize, One, Pi, Sqrt, Zero},
+    Matrix, Vector,
+};
+
+/// Upper bound on the number of sweeps performed by Jacobi iterations.
+const JACOBI_MAX_SWEEPS: usize = 64;
+
+/// Upper bound on the number of QR iterations spent on each eigenvalue, per
+/// row of the matrix (but at least 10 rows).
+#[cfg(feature = "complex")]
+const QR_MAX_ITERATIONS: usize = 30;
+
+/// Number of QR iterations without deflation after which an exceptional
+/// shift is tried.
+#[cfg(feature = "complex")]
+const QR_EXCEPTIONAL_SHIFT: usize = 10;
+
+////////////////////////////////////////////////////////////////////////////////
+// Givens rotations
+////////////////////////////////////////////////////////////////////////////////
+
+impl<const M: usize, const N: usize, T> Matrix<M, N, T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Apply the plane rotation `G = [c s; -s c]` to rows `i` and `j` in place,
+    /// i.e. `A <- G * A` restricted to those rows.
+    ///
+    /// Choosing `c = a / r` and `s = b / r` with `r = sqrt(a² + b²)`, where
+    /// `a = A[(i, k)]` and `b = A[(j, k)]`, zeroes out `A[(j, k)]`.
+    pub fn apply_givens_left(&mut self, i: usize, j: usize, c: T, s: T) {
+        for k in 0..N {
+            let (a, b) = (self[(i, k)], self[(j, k)]);
+            self[(i, k)] = c * a + s * b;
+            self[(j, k)] = c * b - s * a;
+        }
+    }
+
+    /// Apply the plane rotation `G = [c s; -s c]` to columns `i` and `j` in
+    /// place, i.e. `A <- A * Gᵀ` restricted to those columns.
+    ///
+    /// Choosing `c = a / r` and `s = b / r` with `r = sqrt(a² + b²)`, where
+    /// `a = A[(k, i)]` and `b = A[(k, j)]`, zeroes out `A[(k, j)]`.
+    pub fn apply_givens_right(&mut self, i: usize, j: usize, c: T, s: T) {
+        for k in 0..M {
+            let (a, b) = (self[(k, i)], self[(k, j)]);
+            self[(k, i)] = c * a + s * b;
+            self[(k, j)] = c * b - s * a;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Householder reflections
+////////////////////////////////////////////////////////////////////////////////
+
+/// Compute the Householder vector `v` and scalar `beta` such that
+/// `(I - beta v vᵀ) x` has zeros in every entry below `k`.
+///
+/// Entries of `x` above `k` are ignored and the returned `v` is zero there, so
+/// the reflection leaves those rows untouched. `v[k]` is normalized to one.
+pub(crate) fn householder_at<const M: usize, T>(x: &Vector<M, T>, k: usize) -> (Vector<M, T>, T)
+where
+    T: Sqrt
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    let mut v = Vector::<M, T>::zeros();
+    let mut sigma = T::zero();
+    for i in (k + 1)..M {
+        sigma = sigma + x[i] * x[i];
+        v[i] = x[i];
+    }
+    v[k] = T::one();
+    if sigma == T::zero() {
+        return (v, T::zero());
+    }
+
+    let x0 = x[k];
+    let mu = (x0 * x0 + sigma).sqrt();
+    // Avoid cancellation when `x0` is positive (Golub & Van Loan, Alg. 5.1.1)
+    let v0 = if x0 <= T::zero() {
+        x0 - mu
+    } else {
+        -sigma / (x0 + mu)
+    };
+    let beta = (T::one() + T::one()) * v0 * v0 / (sigma + v0 * v0);
+    for i in (k + 1)..M {
+        v[i] = v[i] / v0;
+    }
+    (v, beta)
+}
+
+impl<const M: usize, T> Vector<M, T>
+where
+    T: Sqrt
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Compute the Householder vector `v` and scalar `beta` such that
+    /// `(I - beta v vᵀ) x` is a multiple of the first basis vector `e₁`.
+    ///
+    /// `v[0]` is normalized to one, and `beta` is zero when `x` is already a
+    /// multiple of `e₁`. The reflection can be applied without forming the
+    /// reflector using [`apply_householder_left`][Matrix::apply_householder_left].
+    pub fn householder(x: &Vector<M, T>) -> (Vector<M, T>, T) {
+        householder_at(x, 0)
+    }
+}
+
+impl<const M: usize, const N: usize, T> Matrix<M, N, T>
+where
+    T: Copy + Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Apply the Householder reflection `(I - beta v vᵀ) * A` in place.
+    ///
+    /// This is a rank-1 update costing `O(MN)`, rather than forming the
+    /// `M`-by-`M` reflector and multiplying at `O(M²N)`. See
+    /// [`Vector::householder`] for computing `v` and `beta`.
+    pub fn apply_householder_left(&mut self, v: &Vector<M, T>, beta: T) {
+        for j in 0..N {
+            let mut w = T::zero();
+            for i in 0..M {
+                w = w + v[i] * self[(i, j)];
+            }
+            let w = beta * w;
+            for i in 0..M {
+                self[(i, j)] = self[(i, j)] - w * v[i];
+            }
+        }
+    }
+
+    /// Apply `A * (I - beta v vᵀ)` in place as a rank-1 update.
+    pub(crate) fn apply_householder_right(&mut self, v: &Vector<N, T>, beta: T) {
+        for i in 0..M {
+            let mut w = T::zero();
+            for j in 0..N {
+                w = w + self[(i, j)] * v[j];
+            }
+            let w = beta * w;
+            for j in 0..N {
+                self[(i, j)] = self[(i, j)] - w * v[j];
+            }
+        }
+    }
+}
+
+impl<const N: usize, T> Matrix<N, N, T>
+where
+    T: Sqrt
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Reduce the matrix to upper-Hessenberg form using Householder
+    /// reflections.
+    ///
+    /// Returns `(Q, H)` such that `A == Q * H * Qᵀ`, where `Q` is orthogonal
+    /// and `H` is zero below its first subdiagonal.
+    pub fn hessenberg(&self) -> (Matrix<N, N, T>, Matrix<N, N, T>) {
+        let mut Q = eye!(N, T);
+        let mut H = *self;
+        for k in 0..N.saturating_sub(2) {
+            let mut x = Vector::<N, T>::zeros();
+            for i in (k + 1)..N {
+                x[i] = H[(i, k)];
+            }
+            let (v, beta) = householder_at(&x, k + 1);
+            H.apply_householder_left(&v, beta);
+            H.apply_householder_right(&v, beta);
+            Q.apply_householder_right(&v, beta);
+            // These entries are zero up to rounding, so store them exactly
+            for i in (k + 2)..N {
+                H[(i, k)] = T::zero();
+            }
+        }
+        (Q, H)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Cholesky decomposition
+////////////////////////////////////////////////////////////////////////////////
+
+impl<const N: usize, T> Matrix<N, N, T>
+where
+    T: Sqrt + PartialOrd + Copy + Zero + Mul<Output = T> + Sub<Output = T> + Div<Output = T>,
+{
+    /// Compute the Cholesky decomposition of a symmetric positive-definite
+    /// matrix.
+    ///
+    /// Returns the lower-triangular `L` with `L * Lᵀ == A`, or `None` if a
+    /// diagonal element under the square root is not positive, i.e. the
+    /// matrix is not positive-definite. Only the lower triangle of `A` is
+    /// read.
+    pub fn cholesky(&self) -> Option<Matrix<N, N, T>> {
+        let mut L = Matrix::<N, N, T>::zeros();
+        for j in 0..N {
+            let mut d = self[(j, j)];
+            for k in 0..j {
+                d = d - L[(j, k)] * L[(j, k)];
+            }
+            if d <= T::zero() {
+                return None;
+            }
+            let d = d.sqrt();
+            L[(j, j)] = d;
+            for i in (j + 1)..N {
+                let mut v = self[(i, j)];
+                for k in 0..j {
+                    v = v - L[(i, k)] * L[(j, k)];
+                }
+                L[(i, j)] = v / d;
+            }
+        }
+        Some(L)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Eigenvalues
+////////////////////////////////////////////////////////////////////////////////
+
+impl<const D: usize, T> Matrix<D, D, T>
+where
+    T: Abs
+        + Sqrt
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Compute the eigenvalues and eigenvectors of a symmetric matrix with
+    /// the cyclic Jacobi method.
+    ///
+    /// Returns `(λ, V)` with the eigenvalues in `λ` and the corresponding
+    /// unit eigenvectors in the columns of `V`, so that `A = V diag(λ) Vᵀ`.
+    /// The eigenvalues are not sorted.
+    ///
+    /// Each sweep rotates away every off-diagonal element once. Iteration
+    /// stops when the off-diagonal norm is at most `tol` or after `max_iter`
+    /// sweeps, whichever comes first.
+    ///
+    /// The matrix is assumed to be symmetric, which is not verified.
+    pub fn eig_symmetric(&self, max_iter: usize, tol: T) -> (Matrix<D, 1, T>, Matrix<D, D, T>) {
+        let mut A = *self;
+        let mut V = eye!(D, T);
+        let two = T::one() + T::one();
+
+        for _ in 0..max_iter {
+            let mut off = T::zero();
+            for p in 0..D {
+                for q in (p + 1)..D {
+                    off = off + two * A[(p, q)] * A[(p, q)];
+                }
+            }
+            if off.sqrt() <= tol {
+                break;
+            }
+            for p in 0..D {
+                for q in (p + 1)..D {
+                    if A[(p, q)] == T::zero() {
+                        continue;
+                    }
+                    // Smaller root of t² - 2θt - 1 = 0, which zeroes A[(p, q)]
+                    let theta = (A[(q, q)] - A[(p, p)]) / (two * A[(p, q)]);
+                    let t = T::one() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let t = if theta < T::zero() { t } else { -t };
+                    let c = T::one() / (T::one() + t * t).sqrt();
+                    let s = c * t;
+
+                    A.apply_givens_left(p, q, c, s);
+                    A.apply_givens_right(p, q, c, s);
+                    V.apply_givens_right(p, q, c, s);
+                }
+            }
+        }
+
+        let mut eig = Matrix::<D, 1, T>::zeros();
+        for i in 0..D {
+            eig[i] = A[(i, i)];
+        }
+        (eig, V)
+    }
+}
+
+#[cfg(feature = "complex")]
+impl<const N: usize, T> Matrix<N, N, T>
+where
+    T: Abs
+        + Sqrt
+        + Epsilon
+        + FromUsize
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Compute the eigenvalues of a general square matrix.
+    ///
+    /// The matrix is first reduced to Hessenberg form and then iterated with
+    /// the implicit Francis double-shift QR algorithm, which keeps the
+    /// arithmetic real while still finding complex conjugate pairs. Each
+    /// double-shift sweep chases a bulge down the subdiagonal with 3-element
+    /// Householder reflectors.
+    ///
+    /// Eigenvalues are returned in the order they deflate from the bottom of
+    /// the matrix, with conjugate pairs next to each other and the one with
+    /// positive imaginary part first. Returns `None` if the iteration fails
+    /// to converge.
+    pub fn eigenvalues(&self) -> Option<[Complex<T>; N]> {
+        // This follows LAPACK's DLAHQR without accumulating the Schur form.
+        // LAPACK is Copyright (c) 1992-2017 The University of Tennessee and
+        // The University of Tennessee Research Foundation, Copyright (c)
+        // 2000-2017 The University of California Berkeley, Copyright (c)
+        // 2006-2017 The University of Colorado Denver, distributed under the
+        // modified BSD licence (https://netlib.org/lapack/LICENSE.txt).
+        let (_, mut h) = self.hessenberg();
+        let mut eig = [Complex::new(T::zero(), T::zero()); N];
+        let zero = T::zero();
+        let one = T::one();
+        let half = one / T::from_usize(2);
+        let ulp = T::epsilon();
+        // Ad hoc shift constants
+        let dat1 = T::from_usize(3) / T::from_usize(4);
+        let dat2 = -(T::from_usize(7) / T::from_usize(16));
+        let itmax = QR_MAX_ITERATIONS * N.max(10);
+        let max_min = |a: T, b: T| if a >= b { (a, b) } else { (b, a) };
+
+        // Rows `end..` hold eigenvalues that have already been found
+        let mut end = N;
+        // Iterations since the last deflation
+        let mut kdefl = 0;
+        while end > 0 {
+            let i = end - 1;
+            let mut l = 0;
+            let mut converged = false;
+            for _ in 0..=itmax {
+                // Find the bottom-most negligible subdiagonal element, using
+                // the conservative criterion of Ahues & Tisseur (LAWN 122)
+                l = i;
+                while l > 0 {
+                    let sub = h[(l, l - 1)].abs();
+                    if sub == zero {
+                        break;
+                    }
+                    let mut tst = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+                    if tst == zero {
+                        if l >= 2 {
+                            tst = tst + h[(l - 1, l - 2)].abs();
+                        }
+                        if l + 1 < N {
+                            tst = tst + h[(l + 1, l)].abs();
+                        }
+                    }
+                    if sub <= ulp * tst {
+                        let (ab, ba) = max_min(sub, h[(l - 1, l)].abs());
+                        let (aa, bb) =
+                            max_min(h[(l, l)].abs(), (h[(l - 1, l - 1)] - h[(l, l)]).abs());
+                        let s = aa + ab;
+                        if ba * (ab / s) <= ulp * (bb * (aa / s)) {
+                            break;
+                        }
+                    }
+                    l -= 1;
+                }
+                if l > 0 {
+                    h[(l, l - 1)] = zero;
+                }
+                // A 1-by-1 or 2-by-2 block has split off
+                if l + 1 >= i {
+                    converged = true;
+                    break;
+                }
+                kdefl += 1;
+
+                // Pick the shifts, with an exceptional shift every now and
+                // then to break out of stagnating cycles
+                let (h11, h12, h21, h22) = if kdefl % (2 * QR_EXCEPTIONAL_SHIFT) == 0 {
+                    let s = h[(i, i - 1)].abs() + h[(i - 1, i - 2)].abs();
+                    let h11 = dat1 * s + h[(i, i)];
+                    (h11, dat2 * s, s, h11)
+                } else if kdefl % QR_EXCEPTIONAL_SHIFT == 0 {
+                    let s = h[(l + 1, l)].abs() + h[(l + 2, l + 1)].abs();
+                    let h11 = dat1 * s + h[(l, l)];
+                    (h11, dat2 * s, s, h11)
+                } else {
+                    (h[(i - 1, i - 1)], h[(i - 1, i)], h[(i, i - 1)], h[(i, i)])
+                };
+                let s = h11.abs() + h12.abs() + h21.abs() + h22.abs();
+                let (rt1r, rt1i, rt2r, rt2i) = if s == zero {
+                    (zero, zero, zero, zero)
+                } else {
+                    let (h11, h12, h21, h22) = (h11 / s, h12 / s, h21 / s, h22 / s);
+                    let tr = (h11 + h22) * half;
+                    let det = (h11 - tr) * (h22 - tr) - h12 * h21;
+                    let rtdisc = det.abs().sqrt();
+                    if det >= zero {
+                        // Complex conjugate shifts
+                        (tr * s, rtdisc * s, tr * s, -(rtdisc * s))
+                    } else {
+                        // Real shifts, use the one closer to `h22` twice
+                        let (a, b) = (tr + rtdisc, tr - rtdisc);
+                        let r = if (a - h22).abs() <= (b - h22).abs() {
+                            a
+                        } else {
+                            b
+                        } * s;
+                        (r, zero, r, zero)
+                    }
+                };
+
+                // Look for two consecutive small subdiagonal elements, so the
+                // sweep can start further down
+                let mut m = i - 2;
+                let mut v = [zero; 3];
+                loop {
+                    let h21s = h[(m + 1, m)];
+                    let s = (h[(m, m)] - rt2r).abs() + rt2i.abs() + h21s.abs();
+                    let h21s = h21s / s;
+                    v[0] = h21s * h[(m, m + 1)] + (h[(m, m)] - rt1r) * ((h[(m, m)] - rt2r) / s)
+                        - rt1i * (rt2i / s);
+                    v[1] = h21s * (h[(m, m)] + h[(m + 1, m + 1)] - rt1r - rt2r);
+                    v[2] = h21s * h[(m + 2, m + 1)];
+                    let s = v[0].abs() + v[1].abs() + v[2].abs();
+                    for x in &mut v {
+                        *x = *x / s;
+                    }
+                    if m == l {
+                        break;
+                    }
+                    let h00 = h[(m, m - 1)].abs() * (v[1].abs() + v[2].abs());
+                    let h01 = v[0].abs()
+                        * (h[(m - 1, m - 1)].abs() + h[(m, m)].abs() + h[(m + 1, m + 1)].abs());
+                    if h00 <= ulp * h01 {
+                        break;
+                    }
+                    m -= 1;
+                }
+
+                // Double-shift QR sweep over rows and columns `l..=i`
+                for k in m..i {
+                    let nr = 3.min(i - k + 1);
+                    if k > m {
+                        for (j, x) in v.iter_mut().enumerate().take(nr) {
+                            *x = h[(k + j, k - 1)];
+                        }
+                    }
+                    let (beta, tau) = householder(&mut v[..nr]);
+                    if k > m {
+                        h[(k, k - 1)] = beta;
+                        h[(k + 1, k - 1)] = zero;
+                        if k + 1 < i {
+                            h[(k + 2, k - 1)] = zero;
+                        }
+                    } else if m > l {
+                        // Rather than negating, as this also works when the
+                        // reflector underflows
+                        h[(k, k - 1)] = h[(k, k - 1)] * (one - tau);
+                    }
+                    let (v2, t2) = (v[1], tau * v[1]);
+                    if nr == 3 {
+                        let (v3, t3) = (v[2], tau * v[2]);
+                        for j in k..=i {
+                            let sum = h[(k, j)] + v2 * h[(k + 1, j)] + v3 * h[(k + 2, j)];
+                            h[(k, j)] = h[(k, j)] - sum * tau;
+                            h[(k + 1, j)] = h[(k + 1, j)] - sum * t2;
+                            h[(k + 2, j)] = h[(k + 2, j)] - sum * t3;
+                        }
+                        for j in l..=(k + 3).min(i) {
+                            let sum = h[(j, k)] + v2 * h[(j, k + 1)] + v3 * h[(j, k + 2)];
+                            h[(j, k)] = h[(j, k)] - sum * tau;
+                            h[(j, k + 1)] = h[(j, k + 1)] - sum * t2;
+                            h[(j, k + 2)] = h[(j, k + 2)] - sum * t3;
+                        }
+                    } else {
+                        for j in k..=i {
+                            let sum = h[(k, j)] + v2 * h[(k + 1, j)];
+                            h[(k, j)] = h[(k, j)] - sum * tau;
+                            h[(k + 1, j)] = h[(k + 1, j)] - sum * t2;
+                        }
+                        for j in l..=i {
+                            let sum = h[(j, k)] + v2 * h[(j, k + 1)];
+                            h[(j, k)] = h[(j, k)] - sum * tau;
+                            h[(j, k + 1)] = h[(j, k + 1)] - sum * t2;
+                        }
+                    }
+                }
+            }
+            if !converged {
+                return None;
+            }
+
+            if l == i {
+                eig[i] = Complex::new(h[(i, i)], zero);
+            } else {
+                let [(r1, i1), (r2, i2)] =
+                    eigenvalues_2x2(h[(i - 1, i - 1)], h[(i - 1, i)], h[(i, i - 1)], h[(i, i)]);
+                eig[i - 1] = Complex::new(r1, i1);
+                eig[i] = Complex::new(r2, i2);
+            }
+            kdefl = 0;
+            end = l;
+        }
+        Some(eig)
+    }
+}
+
+/// Compute the elementary reflector `H = I - τ [1; u] [1; u]ᵀ` with
+/// `H v = [β; 0]`, overwriting the tail of `v` with `u` (LAPACK's DLARFG).
+///
+/// Returns `(β, τ)`. If the tail of `v` is already zero then `τ = 0` and `H`
+/// is the identity.
+#[cfg(feature = "complex")]
+fn householder<T>(v: &mut [T]) -> (T, T)
+where
+    T: Abs
+        + Sqrt
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    let (alpha, tail) = v.split_first_mut().unwrap();
+    let xnorm_sqr = tail.iter().fold(T::zero(), |acc, &x| acc + x * x);
+    if xnorm_sqr == T::zero() {
+        return (*alpha, T::zero());
+    }
+    let norm = (*alpha * *alpha + xnorm_sqr).sqrt();
+    let beta = if *alpha >= T::zero() { -norm } else { norm };
+    let tau = (beta - *alpha) / beta;
+    let scale = T::one() / (*alpha - beta);
+    for x in tail {
+        *x = *x * scale;
+    }
+    *alpha = beta;
+    (beta, tau)
+}
+
+/// Compute the eigenvalues `(re, im)` of the 2-by-2 block `[a b; c d]`,
+/// following LAPACK's DLANV2.
+///
+/// For a complex conjugate pair the eigenvalue with positive imaginary part
+/// comes first.
+#[cfg(feature = "complex")]
+fn eigenvalues_2x2<T>(mut a: T, mut b: T, mut c: T, mut d: T) -> [(T, T); 2]
+where
+    T: Abs
+        + Sqrt
+        + Epsilon
+        + FromUsize
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    let zero = T::zero();
+    let one = T::one();
+    let half = one / T::from_usize(2);
+    let signum = |x: T| if x >= zero { one } else { -one };
+
+    if c == zero {
+        // Already upper triangular
+    } else if b == zero {
+        // Lower triangular, swap the diagonal
+        core::mem::swap(&mut a, &mut d);
+        b = -c;
+        c = zero;
+    } else if a - d == zero && signum(b) != signum(c) {
+        // Already in standard form with complex eigenvalues
+    } else {
+        let temp = a - d;
+        let p = half * temp;
+        let bcmax = if b.abs() >= c.abs() { b.abs() } else { c.abs() };
+        let bcmis = if b.abs() >= c.abs() { c.abs() } else { b.abs() } * signum(b) * signum(c);
+        let scale = if p.abs() >= bcmax { p.abs() } else { bcmax };
+        let z = (p / scale) * p + (bcmax / scale) * bcmis;
+        if z >= T::from_usize(4) * T::epsilon() {
+            // Real eigenvalues
+            let z = p + signum(p) * scale.sqrt() * z.sqrt();
+            a = d + z;
+            d = d - (bcmax / z) * bcmis;
+            c = zero;
+        } else {
+            // Complex or almost equal real eigenvalues: rotate to make the
+            // diagonal equal
+            let sigma = b + c;
+            let tau = (sigma * sigma + temp * temp).sqrt();
+            let cs = (half * (one + sigma.abs() / tau)).sqrt();
+            let sn = -(p / (tau * cs)) * signum(sigma);
+
+            let aa = a * cs + b * sn;
+            let bb = -a * sn + b * cs;
+            let cc = c * cs + d * sn;
+            let dd = -c * sn + d * cs;
+
+            b = bb * cs + dd * sn;
+            c = -aa * sn + cc * cs;
+            let temp = half * ((aa * cs + cc * sn) + (-bb * sn + dd * cs));
+            a = temp;
+            d = temp;
+
+            if c != zero {
+                if b == zero {
+                    c = zero;
+                } else if signum(b) == signum(c) {
+                    // Real after all
+                    let p = signum(c) * b.abs().sqrt() * c.abs().sqrt();
+                    a = temp + p;
+                    d = temp - p;
+                    c = zero;
+                }
+            }
+        }
+    }
+
+    if c == zero {
+        [(a, zero), (d, zero)]
+    } else {
+        let im = b.abs().sqrt() * c.abs().sqrt();
+        [(a, im), (d, -im)]
+    }
+}
+
+impl<T> Matrix<3, 3, T>
+where
+    T: Sqrt
+        + Acos
+        + Cos
+        + Pi
+        + FromUsize
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Compute the eigenvalues of a symmetric 3-by-3 matrix in closed form.
+    ///
+    /// Uses the trigonometric solution of the characteristic cubic, so no
+    /// iteration is needed. Only the upper triangle is read. The eigenvalues
+    /// are returned in descending order.
+    pub fn symmetric_eigenvalues_3x3(&self) -> [T; 3] {
+        let a = self;
+        let p1 = a[(0, 1)] * a[(0, 1)] + a[(0, 2)] * a[(0, 2)] + a[(1, 2)] * a[(1, 2)];
+        if p1 == T::zero() {
+            // Already diagonal
+            let mut eig = [a[(0, 0)], a[(1, 1)], a[(2, 2)]];
+            for i in 1..3 {
+                let mut j = i;
+                while j > 0 && eig[j - 1] < eig[j] {
+                    eig.swap(j - 1, j);
+                    j -= 1;
+                }
+            }
+            return eig;
+        }
+
+        let two = T::from_usize(2);
+        let three = T::from_usize(3);
+        let q = (a[(0, 0)] + a[(1, 1)] + a[(2, 2)]) / three;
+        let (d0, d1, d2) = (a[(0, 0)] - q, a[(1, 1)] - q, a[(2, 2)] - q);
+        let p2 = d0 * d0 + d1 * d1 + d2 * d2 + two * p1;
+        let p = (p2 / T::from_usize(6)).sqrt();
+
+        // r = det((A - qI) / p) / 2, clamped to [-1, 1] against rounding
+        let (b01, b02, b12) = (a[(0, 1)], a[(0, 2)], a[(1, 2)]);
+        let det = d0 * (d1 * d2 - b12 * b12) - b01 * (b01 * d2 - b12 * b02)
+            + b02 * (b01 * b12 - d1 * b02);
+        let r = det / (two * p * p * p);
+        let phi = if r <= T::zero() - T::one() {
+            T::pi() / three
+        } else if r >= T::one() {
+            T::zero()
+        } else {
+            r.acos() / three
+        };
+
+        let e0 = q + two * p * phi.cos();
+        let e2 = q + two * p * (phi + two * T::pi() / three).cos();
+        let e1 = three * q - e0 - e2;
+        [e0, e1, e2]
+    }
+}
+
+impl<T> Matrix<2, 2, T>
+where
+    T: Sqrt
+        + Atan2
+        + Copy
+        + One
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Compute the principal values and axes of a symmetric 2-by-2 matrix.
+    ///
+    /// Returns `(λ₁, λ₂, θ)` with `λ₁ >= λ₂` the eigenvalues and `θ` the angle
+    /// from the x-axis to the eigenvector of `λ₁`, in `[-π/2, π/2]`. This is
+    /// the Mohr's circle construction: the eigenvalues are its center plus
+    /// and minus its radius. Only the upper triangle is read.
+    pub fn principal_2x2(&self) -> (T, T, T) {
+        let two = T::one() + T::one();
+        let (a, b, d) = (self[(0, 0)], self[(0, 1)], self[(1, 1)]);
+        let center = (a + d) / two;
+        let half_diff = (a - d) / two;
+        let radius = (half_diff * half_diff + b * b).sqrt();
+        let theta = (two * b).atan2(a - d) / two;
+        (center + radius, center - radius, theta)
+    }
+}
+
+impl<const M: usize, const N: usize, T> Matrix<M, N, T>
+where
+    T: Abs
+        + Sqrt
+        + Epsilon
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Orthogonalize the columns of this matrix using one-sided Jacobi
+    /// rotations.
+    ///
+    /// Returns `(W, σ, V)` such that `A * V == W`, where `V` is orthogonal and
+    /// the columns of `W` are mutually orthogonal. The singular values `σ` of
+    /// `A` are the norms of the columns of `W`, so `W = U * diag(σ)`.
+    pub(crate) fn jacobi_svd(&self) -> (Matrix<M, N, T>, [T; N], Matrix<N, N, T>) {
+        let mut W = *self;
+        let mut V = eye!(N, T);
+        let two = T::one() + T::one();
+
+        for _ in 0..JACOBI_MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..N {
+                for q in (p + 1)..N {
+                    let mut alpha = T::zero();
+                    let mut beta = T::zero();
+                    let mut gamma = T::zero();
+                    for i in 0..M {
+                        alpha = alpha + W[(i, p)] * W[(i, p)];
+                        beta = beta + W[(i, q)] * W[(i, q)];
+                        gamma = gamma + W[(i, p)] * W[(i, q)];
+                    }
+                    // Skip columns which are already orthogonal to working precision
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let t = T::one() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                    let t = if zeta < T::zero() { -t } else { t };
+                    let c = T::one() / (T::one() + t * t).sqrt();
+                    let s = c * t;
+
+                    W.apply_givens_right(p, q, c, -s);
+                    V.apply_givens_right(p, q, c, -s);
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut sigma = [T::zero(); N];
+        for (j, s) in sigma.iter_mut().enumerate() {
+            let mut sq = T::zero();
+            for i in 0..M {
+                sq = sq + W[(i, j)] * W[(i, j)];
+            }
+            *s = sq.sqrt();
+        }
+        (W, sigma, V)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::{diag, eye, matrix, vector, Vector};
+
+    #[test]
+    fn givens() {
+        let mut A = matrix![
+            3.0, 1.0, 2.0;
+            4.0, 5.0, 6.0;
+        ];
+        let r = libm::sqrt(3.0 * 3.0 + 4.0 * 4.0);
+        let (c, s) = (3.0 / r, 4.0 / r);
+        A.apply_givens_left(0, 1, c, s);
+        let exp = matrix![
+            5.0, 4.6, 6.0;
+            0.0, 2.2, 2.0;
+        ];
+        assert_relative_eq!(A, exp, epsilon = 1e-12);
+
+        let mut A = matrix![
+            3.0, 4.0;
+            1.0, 2.0;
+        ];
+        A.apply_givens_right(0, 1, 0.6, 0.8);
+        let exp = matrix![
+            5.0, 0.0;
+            2.2, 0.4;
+        ];
+        assert_relative_eq!(A, exp, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn householder() {
+        let x = vector![3.0; 1.0; 5.0; 1.0];
+        let (v, beta) = Vector::householder(&x);
+        assert_eq!(v[0], 1.0);
+        let H = eye!(4, f64) - v * v.T() * beta;
+        let y = H * x;
+        assert_relative_eq!(y[0].abs(), 6.0, epsilon = 1e-12);
+        for i in 1..4 {
+            assert_relative_eq!(y[i], 0.0, epsilon = 1e-12);
+        }
+        // The reflector is orthogonal
+        assert_relative_eq!(H * H.T(), eye!(4, f64), epsilon = 1e-12);
+
+        // Already a multiple of e₁
+        let (_, beta) = Vector::householder(&vector![-2.0; 0.0; 0.0]);
+        assert_eq!(beta, 0.0);
+    }
+
+    #[test]
+    fn apply_householder_left() {
+        let A = matrix![
+            3.0, 1.0, 0.0;
+            1.0, 4.0, 2.0;
+            5.0, 0.0, 1.0;
+            1.0, 2.0, 6.0;
+        ];
+        let x = vector![3.0; 1.0; 5.0; 1.0];
+        let (v, beta) = Vector::householder(&x);
+
+        let mut B = A;
+        B.apply_householder_left(&v, beta);
+        let H = eye!(4, f64) - v * v.T() * beta;
+        assert_relative_eq!(B, H * A, epsilon = 1e-12);
+        // The first column of A is `x`, which is reflected onto e₁
+        for i in 1..4 {
+            assert_relative_eq!(B[(i, 0)], 0.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn hessenberg() {
+        let A = matrix![
+            4.0, 1.0, 2.0, 3.0;
+            2.0, 5.0, 1.0, 7.0;
+            6.0, 1.0, 3.0, 2.0;
+            1.0, 8.0, 2.0, 4.0;
+        ];
+        let (Q, H) = A.hessenberg();
+        assert_relative_eq!(Q * H * Q.T(), A, epsilon = 1e-12);
+        assert_relative_eq!(Q.T() * Q, crate::eye!(4, f64), epsilon = 1e-12);
+        for j in 0..4 {
+            for i in (j + 2)..4 {
+                assert_eq!(H[(i, j)], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn cholesky() {
+        let A = matrix![
+            4.0, 12.0, -16.0;
+            12.0, 37.0, -43.0;
+            -16.0, -43.0, 98.0;
+        ];
+        let L = A.cholesky().unwrap();
+        let exp = matrix![
+            2.0, 0.0, 0.0;
+            6.0, 1.0, 0.0;
+            -8.0, 5.0, 3.0;
+        ];
+        assert_relative_eq!(L, exp, epsilon = 1e-12);
+        assert_relative_eq!(L * L.T(), A, epsilon = 1e-12);
+
+        // Symmetric but indefinite
+        let A = matrix![
+            1.0, 2.0;
+            2.0, 1.0;
+        ];
+        assert_eq!(A.cholesky(), None);
+    }
+
+    #[test]
+    fn eig_symmetric() {
+        let A = matrix![
+            3.0, 0.0, 0.0;
+            0.0, -1.0, 0.0;
+            0.0, 0.0, 2.0;
+        ];
+        let (eig, V) = A.eig_symmetric(10, 1e-12);
+        assert_eq!(eig, vector![3.0; -1.0; 2.0]);
+        assert_eq!(V, eye!(3));
+
+        // Eigenvalues 3 and 1 with eigenvectors (1, 1) and (1, -1)
+        let A = matrix![
+            2.0, 1.0;
+            1.0, 2.0_f64;
+        ];
+        let (eig, V) = A.eig_symmetric(10, 1e-12);
+        let (hi, lo) = if eig[0] > eig[1] { (0, 1) } else { (1, 0) };
+        assert_relative_eq!(eig[hi], 3.0, epsilon = 1e-12);
+        assert_relative_eq!(eig[lo], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(V[(0, hi)].abs(), V[(1, hi)].abs(), epsilon = 1e-12);
+        assert_relative_eq!(V[(0, lo)], -V[(1, lo)], epsilon = 1e-12);
+
+        let A = matrix![
+            4.0, 1.0, 2.0, 0.5;
+            1.0, 5.0, 3.0, 1.0;
+            2.0, 3.0, 6.0, 0.0;
+            0.5, 1.0, 0.0, 1.0;
+        ];
+        let (eig, V) = A.eig_symmetric(30, 1e-12);
+        let mut L = crate::Matrix::<4, 4, f64>::zeros();
+        for i in 0..4 {
+            L[(i, i)] = eig[i];
+        }
+        assert_relative_eq!(V.T() * V, eye!(4, f64), epsilon = 1e-12);
+        assert_relative_eq!(V * L * V.T(), A, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn symmetric_eigenvalues_3x3() {
+        let A = matrix![
+            4.0, 1.0, 2.0;
+            1.0, 5.0, 3.0;
+            2.0, 3.0, 6.0;
+        ];
+        let eig = A.symmetric_eigenvalues_3x3();
+        assert!(eig[0] >= eig[1] && eig[1] >= eig[2]);
+
+        // A is positive-definite, so its singular values are its eigenvalues
+        let (_, mut sigma, _) = A.jacobi_svd();
+        sigma.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        for (e, s) in eig.iter().zip(sigma.iter()) {
+            assert_relative_eq!(e, s, epsilon = 1e-10);
+        }
+        let (I1, _, I3) = A.invariants();
+        assert_relative_eq!(eig.iter().sum::<f64>(), I1, epsilon = 1e-10);
+        assert_relative_eq!(eig.iter().product::<f64>(), I3, epsilon = 1e-10);
+
+        // Indefinite, with a repeated eigenvalue: 2, -1, -1
+        let A = matrix![
+            0.0, 1.0, 1.0;
+            1.0, 0.0, 1.0;
+            1.0, 1.0, 0.0;
+        ];
+        let eig = A.symmetric_eigenvalues_3x3();
+        assert_relative_eq!(eig[0], 2.0, epsilon = 1e-10);
+        assert_relative_eq!(eig[1], -1.0, epsilon = 1e-10);
+        assert_relative_eq!(eig[2], -1.0, epsilon = 1e-10);
+
+        let A = diag!(1.0, 3.0, 2.0);
+        assert_eq!(A.symmetric_eigenvalues_3x3(), [3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn principal_2x2() {
+        let A = matrix![
+            3.0, 1.0;
+            1.0, 3.0;
+        ];
+        let (l1, l2, theta) = A.principal_2x2();
+        assert_relative_eq!(l1, 4.0, epsilon = 1e-12);
+        assert_relative_eq!(l2, 2.0, epsilon = 1e-12);
+        assert_relative_eq!(theta, core::f64::consts::FRAC_PI_4, epsilon = 1e-12);
+
+        // Plane stress σx = 50, σy = -10, τxy = 40: center 20, radius 50
+        let A = matrix![
+            50.0,  40.0;
+            40.0, -10.0;
+        ];
+        let (l1, l2, theta) = A.principal_2x2();
+        assert_relative_eq!(l1, 70.0, epsilon = 1e-12);
+        assert_relative_eq!(l2, -30.0, epsilon = 1e-12);
+        let v = vector![libm::cos(theta); libm::sin(theta)];
+        assert_relative_eq!(A * v, v * l1, epsilon = 1e-12);
+
+        let A = matrix![
+            1.0, 0.0;
+            0.0, 2.0;
+        ];
+        let (l1, l2, theta) = A.principal_2x2();
+        assert_eq!((l1, l2), (2.0, 1.0));
+        assert_relative_eq!(theta, core::f64::consts::FRAC_PI_2, epsilon = 1e-12);
+    }
+
+    #[cfg(feature = "complex")]
+    #[test]
+    fn eigenvalues() {
+        use crate::{Complex, Matrix};
+
+        fn sorted<const N: usize>(mut e: [Complex<f64>; N]) -> [Complex<f64>; N] {
+            e.sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap());
+            e
+        }
+        fn assert_close<const N: usize>(a: [Complex<f64>; N], b: [Complex<f64>; N]) {
+            for (a, b) in sorted(a).iter().zip(sorted(b).iter()) {
+                assert_relative_eq!(a.re, b.re, epsilon = 1e-10);
+                assert_relative_eq!(a.im, b.im, epsilon = 1e-10);
+            }
+        }
+
+        // 2D rotation by θ has eigenvalues cos θ ± i sin θ
+        let (s, c) = (libm::sin(0.5), libm::cos(0.5));
+        let R = matrix![
+            c, -s;
+            s,  c;
+        ];
+        let exp = [Complex::new(c, -s), Complex::new(c, s)];
+        assert_close(R.eigenvalues().unwrap(), exp);
+
+        // 3D rotation about an oblique axis has eigenvalues 1, cos θ ± i sin θ
+        let Rx = matrix![
+            1.0, 0.0, 0.0;
+            0.0,   c,  -s;
+            0.0,   s,   c;
+        ];
+        let Rz = matrix![
+              c,  -s, 0.0;
+              s,   c, 0.0;
+            0.0, 0.0, 1.0;
+        ];
+        let R = Rz * Rx * Rz.T();
+        let exp = [
+            Complex::new(c, -s),
+            Complex::new(c, s),
+            Complex::new(1.0, 0.0),
+        ];
+        assert_close(R.eigenvalues().unwrap(), exp);
+
+        // Real eigenvalues of a non-symmetric matrix
+        let A = matrix![
+            2.0, 0.0, 0.0, 0.0;
+            1.0, 3.0, 0.0, 0.0;
+            4.0, 1.0, 5.0, 0.0;
+            7.0, 2.0, 1.0, 1.0;
+        ];
+        let B = matrix![
+            1.0, 2.0, 0.0, 1.0;
+            0.0, 1.0, 3.0, 0.0;
+            1.0, 0.0, 1.0, 2.0;
+            0.0, 1.0, 0.0, 1.0;
+        ];
+        let A = B * A * B.inv().unwrap();
+        let exp = [
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 0.0),
+            Complex::new(3.0, 0.0),
+            Complex::new(5.0, 0.0),
+        ];
+        assert_close(A.eigenvalues().unwrap(), exp);
+
+        // The cyclic shift has the 5th roots of unity as eigenvalues. Plain
+        // shifts stagnate on it, so this needs the exceptional shifts.
+        let P = Matrix::<5, 5, f64>::from_fn(|i, j| if (i + 4) % 5 == j { 1.0 } else { 0.0 });
+        let tau = 2.0 * core::f64::consts::PI;
+        let exp = core::array::from_fn(|k| {
+            let t = tau * k as f64 / 5.0;
+            Complex::new(libm::cos(t), libm::sin(t))
+        });
+        assert_close(P.eigenvalues().unwrap(), exp);
+
+        // Companion matrix of (x² + 1)(x - 2)(x + 3) = x⁴ + x³ - 5x² + x - 6
+        let C = matrix![
+            0.0, 0.0, 0.0,  6.0;
+            1.0, 0.0, 0.0, -1.0;
+            0.0, 1.0, 0.0,  5.0;
+            0.0, 0.0, 1.0, -1.0;
+        ];
+        let e = C.eigenvalues().unwrap();
+        let exp = [
+            Complex::new(-3.0, 0.0),
+            Complex::new(0.0, -1.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(2.0, 0.0),
+        ];
+        assert_close(e, exp);
+        // Conjugate pairs are adjacent, positive imaginary part first
+        let k = e.iter().position(|z| z.im > 0.5).unwrap();
+        assert_relative_eq!(e[k + 1].im, -1.0, epsilon = 1e-10);
+
+        // A dense matrix: the eigenvalues sum to the trace and multiply to
+        // the determinant
+        let A = Matrix::<6, 6, f64>::from_fn(|i, j| libm::sin((7 * i + 3 * j * j + 1) as f64));
+        let e = A.eigenvalues().unwrap();
+        let sum = e.iter().fold(Complex::new(0.0, 0.0), |acc, &z| acc + z);
+        let prod = e.iter().fold(Complex::new(1.0, 0.0), |acc, &z| acc * z);
+        assert_relative_eq!(sum.re, A.trace(), epsilon = 1e-10);
+        assert_relative_eq!(sum.im, 0.0, epsilon = 1e-10);
+        assert_relative_eq!(prod.re, A.det(), epsilon = 1e-10);
+        assert_relative_eq!(prod.im, 0.0, epsilon = 1e-10);
+
+        // Trivial sizes
+        assert_eq!(matrix![-2.5].eigenvalues(), Some([Complex::new(-2.5, 0.0)]));
+        assert_eq!(
+            Matrix::<3, 3, f64>::zeros().eigenvalues(),
+            Some([Complex::new(0.0, 0.0); 3])
+        );
+        assert_eq!(Matrix::<0, 0, f64>::zeros().eigenvalues(), Some([]));
+    }
+
+    #[test]
+    fn jacobi_svd() {
+        let A = matrix![
+            3.0, 2.0,  2.0;
+            2.0, 3.0, -2.0;
+        ];
+        let (W, mut sigma, V) = A.jacobi_svd();
+        assert_relative_eq!(A * V, W, max_relative = 1e-12);
+        assert_relative_eq!(V.T() * V, crate::eye!(3, f64), epsilon = 1e-12);
+
+        sigma.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        assert_relative_eq!(sigma[0], 5.0, max_relative = 1e-12);
+        assert_relative_eq!(sigma[1], 3.0, max_relative = 1e-12);
+        assert_relative_eq!(sigma[2], 0.0, epsilon = 1e-12);
+    }
+}
@@ -16,15 +16,82 @@ impl<T: fmt::Debug, const M: usize, const N: usize> fmt::Debug for Matrix<M, N,
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Display
+////////////////////////////////////////////////////////////////////////////////
+
+/// A writer that only counts the characters written to it.
+struct Width(usize);
+
+impl fmt::Write for Width {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Write `x` using the precision of `f` if one was given.
+fn write_elem<W: fmt::Write, T: fmt::Display>(
+    w: &mut W,
+    x: &T,
+    precision: Option<usize>,
+) -> fmt::Result {
+    match precision {
+        Some(p) => write!(w, "{:.*}", p, x),
+        None => write!(w, "{}", x),
+    }
+}
+
 impl<T: fmt::Display, const M: usize, const N: usize> fmt::Display for Matrix<M, N, T> {
+    /// Print one line per row, with the columns right-aligned to a common
+    /// width and separated by a space. A precision such as `{:.3}` is applied
+    /// to every element.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "\n")?;
+        let precision = f.precision();
+        let mut width = 0;
+        for x in self.iter() {
+            let mut w = Width(0);
+            write_elem(&mut w, x, precision)?;
+            width = width.max(w.0);
+        }
         for r in 0..M {
+            if r > 0 {
+                writeln!(f)?;
+            }
             for c in 0..N {
-                writeln!(f, "{:2.11} ", self[(r, c)])?;
+                if c > 0 {
+                    write!(f, " ")?;
+                }
+                let x = &self[(r, c)];
+                let mut w = Width(0);
+                write_elem(&mut w, x, precision)?;
+                for _ in w.0..width {
+                    write!(f, " ")?;
+                }
+                write_elem(f, x, precision)?;
             }
-            writeln!(f, "\n")?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use crate::matrix;
+
+    #[test]
+    fn display() {
+        let m = matrix![
+            1.0, -2.5;
+            10.0, 3.0;
+        ];
+        assert_eq!(format!("{}", m), "   1 -2.5\n  10    3");
+        assert_eq!(format!("{:.3}", m), " 1.000 -2.500\n10.000  3.000");
+
+        let v = matrix![1, 22, 333];
+        assert_eq!(format!("{}", v), "  1  22 333");
+    }
+}
@@ -111,12 +111,16 @@ unsafe impl<T, const M: usize, const N: usize> MatrixIndex<Matrix<M, N, T>> for
 
     #[inline]
     fn get(self, matrix: &Matrix<M, N, T>) -> Option<&Self::Output> {
-        matrix.as_slice().get(self.1 * M + self.0)
+        matrix
+            .as_slice()
+            .get(Matrix::<M, N, T>::flat_index(self.0, self.1))
     }
 
     #[inline]
     fn get_mut(self, matrix: &mut Matrix<M, N, T>) -> Option<&mut Self::Output> {
-        matrix.as_mut_slice().get_mut(self.1 * M + self.0)
+        matrix
+            .as_mut_slice()
+            .get_mut(Matrix::<M, N, T>::flat_index(self.0, self.1))
     }
 
     #[inline]
@@ -124,7 +128,7 @@ unsafe impl<T, const M: usize, const N: usize> MatrixIndex<Matrix<M, N, T>> for
         // SAFETY: it is the caller's responsibility not to call this with an
         // out-of-bounds index or a dangling `matrix` pointer.
         let matrix = unsafe { (*matrix).as_slice() };
-        unsafe { matrix.get_unchecked(self.1 * M + self.0) }
+        unsafe { matrix.get_unchecked(Matrix::<M, N, T>::flat_index(self.0, self.1)) }
     }
 
     #[inline]
@@ -132,18 +136,18 @@ unsafe impl<T, const M: usize, const N: usize> MatrixIndex<Matrix<M, N, T>> for
         // SAFETY: it is the caller's responsibility not to call this with an
         // out-of-bounds index or a dangling `matrix` pointer.
         let matrix = unsafe { (*matrix).as_mut_slice() };
-        unsafe { matrix.get_unchecked_mut(self.1 * M + self.0) }
+        unsafe { matrix.get_unchecked_mut(Matrix::<M, N, T>::flat_index(self.0, self.1)) }
     }
 
     #[track_caller]
     #[inline]
     fn index(self, matrix: &Matrix<M, N, T>) -> &Self::Output {
-        &matrix.as_slice()[self.1 * M + self.0]
+        &matrix.as_slice()[Matrix::<M, N, T>::flat_index(self.0, self.1)]
     }
 
     #[track_caller]
     #[inline]
     fn index_mut(self, matrix: &mut Matrix<M, N, T>) -> &mut Self::Output {
-        &mut matrix.as_mut_slice()[self.1 * M + self.0]
+        &mut matrix.as_mut_slice()[Matrix::<M, N, T>::flat_index(self.0, self.1)]
     }
 }
@@ -0,0 +1,230 @@
+#![allow(non_snake_case)]
+
+//! Iterative solvers for linear systems.
+
+use core::{
+    iter::Sum,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::{
+    num::{Abs, Sqrt, Zero},
+    Matrix, Vector,
+};
+
+/// Dot product of two column vectors.
+fn dot<const N: usize, T>(a: &Vector<N, T>, b: &Vector<N, T>) -> T
+where
+    T: Copy + Mul<Output = T> + Sum,
+{
+    (0..N).map(|i| a[i] * b[i]).sum()
+}
+
+impl<const N: usize, T> Matrix<N, N, T>
+where
+    T: Sqrt
+        + PartialOrd
+        + Copy
+        + Zero
+        + Sum
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Solve `A x = b` for a symmetric positive-definite `A` using the
+    /// conjugate gradient method.
+    ///
+    /// Iterates from `x = 0` until the residual norm `||b - A x||` is at most
+    /// `tol`. Returns `None` if this does not happen within `max_iters`
+    /// iterations or if `A` turns out not to be positive-definite.
+    pub fn solve_cg(&self, b: &Vector<N, T>, tol: T, max_iters: usize) -> Option<Vector<N, T>> {
+        let mut x = Vector::<N, T>::zeros();
+        let mut r = *b;
+        let mut p = r;
+        let mut rr = dot(&r, &r);
+        if rr.sqrt() <= tol {
+            return Some(x);
+        }
+        for _ in 0..max_iters {
+            let Ap = self * p;
+            let pAp = dot(&p, &Ap);
+            if pAp <= T::zero() {
+                return None;
+            }
+            let alpha = rr / pAp;
+            x = x + p * alpha;
+            r = r - Ap * alpha;
+            let rr_next = dot(&r, &r);
+            if rr_next.sqrt() <= tol {
+                return Some(x);
+            }
+            p = r + p * (rr_next / rr);
+            rr = rr_next;
+        }
+        None
+    }
+
+    /// Check whether the matrix is (weakly) diagonally dominant by rows, i.e.
+    /// `|a_ii| >= Σ_{j≠i} |a_ij|` for every row `i`.
+    ///
+    /// Jacobi and Gauss-Seidel iterations are guaranteed to converge when the
+    /// inequality is strict for every row.
+    pub fn is_diagonally_dominant(&self) -> bool
+    where
+        T: Abs,
+    {
+        (0..N).all(|i| {
+            let off = (0..N)
+                .filter(|&j| j != i)
+                .map(|j| self[(i, j)].abs())
+                .sum::<T>();
+            self[(i, i)].abs() >= off
+        })
+    }
+
+    /// Solve `A x = b` with Jacobi iteration.
+    ///
+    /// Converges for strictly diagonally dominant matrices. Iterates from
+    /// `x = 0` until the residual norm is at most `tol`, returning `None` if
+    /// this does not happen within `max_iters` sweeps or if a diagonal element
+    /// is zero.
+    pub fn solve_jacobi(&self, b: &Vector<N, T>, tol: T, max_iters: usize) -> Option<Vector<N, T>> {
+        self.solve_stationary(b, tol, max_iters, false)
+    }
+
+    /// Solve `A x = b` with Gauss-Seidel iteration.
+    ///
+    /// Like [`solve_jacobi`][Self::solve_jacobi], but each update uses the
+    /// components already computed in the current sweep, which usually
+    /// converges faster.
+    pub fn solve_gauss_seidel(
+        &self,
+        b: &Vector<N, T>,
+        tol: T,
+        max_iters: usize,
+    ) -> Option<Vector<N, T>> {
+        self.solve_stationary(b, tol, max_iters, true)
+    }
+
+    /// Shared sweep for the Jacobi (`in_place == false`) and Gauss-Seidel
+    /// (`in_place == true`) iterations.
+    fn solve_stationary(
+        &self,
+        b: &Vector<N, T>,
+        tol: T,
+        max_iters: usize,
+        in_place: bool,
+    ) -> Option<Vector<N, T>> {
+        if (0..N).any(|i| self[(i, i)] == T::zero()) {
+            return None;
+        }
+        let mut x = Vector::<N, T>::zeros();
+        for _ in 0..max_iters {
+            let prev = x;
+            for i in 0..N {
+                let src = if in_place { &x } else { &prev };
+                let mut sum = b[i];
+                for j in 0..N {
+                    if j != i {
+                        sum = sum - self[(i, j)] * src[j];
+                    }
+                }
+                x[i] = sum / self[(i, i)];
+            }
+            let r = b - self * x;
+            if dot(&r, &r).sqrt() <= tol {
+                return Some(x);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::{matrix, vector};
+
+    #[test]
+    fn solve_cg() {
+        let A = matrix![
+            4.0, 1.0, 0.0, 0.0;
+            1.0, 4.0, 1.0, 0.0;
+            0.0, 1.0, 4.0, 1.0;
+            0.0, 0.0, 1.0, 3.0;
+        ];
+        let b = vector![1.0; 2.0; 0.0; 1.0];
+        let x = A.solve_cg(&b, 1e-10, 100).unwrap();
+        assert!((b - A * x).norm() <= 1e-10);
+
+        // Not enough iterations to converge
+        assert_eq!(A.solve_cg(&b, 1e-10, 1), None);
+
+        // Indefinite matrix breaks down
+        let A = matrix![
+            1.0, 0.0;
+            0.0, -1.0;
+        ];
+        assert_eq!(A.solve_cg(&vector![0.0; 1.0], 1e-10, 10), None);
+    }
+
+    #[test]
+    fn solve_jacobi() {
+        let A = matrix![
+            10.0, -1.0,  2.0,  0.0;
+            -1.0, 11.0, -1.0,  3.0;
+             2.0, -1.0, 10.0, -1.0;
+             0.0,  3.0, -1.0,  8.0;
+        ];
+        let b = vector![6.0; 25.0; -11.0; 15.0];
+        let exp = vector![1.0; 2.0; -1.0; 1.0];
+
+        let x = A.solve_jacobi(&b, 1e-10, 100).unwrap();
+        assert_relative_eq!(x, exp, epsilon = 1e-9);
+        assert_eq!(A.solve_jacobi(&b, 1e-10, 2), None);
+
+        let zero_diag = matrix![
+            0.0, 1.0;
+            1.0, 0.0;
+        ];
+        assert_eq!(zero_diag.solve_jacobi(&vector![1.0; 1.0], 1e-10, 10), None);
+    }
+
+    #[test]
+    fn solve_gauss_seidel() {
+        let A = matrix![
+            10.0, -1.0,  2.0,  0.0;
+            -1.0, 11.0, -1.0,  3.0;
+             2.0, -1.0, 10.0, -1.0;
+             0.0,  3.0, -1.0,  8.0;
+        ];
+        let b = vector![6.0; 25.0; -11.0; 15.0];
+        let exp = vector![1.0; 2.0; -1.0; 1.0];
+
+        let x = A.solve_gauss_seidel(&b, 1e-10, 100).unwrap();
+        assert_relative_eq!(x, exp, epsilon = 1e-9);
+
+        // Gauss-Seidel converges in fewer sweeps than Jacobi here
+        assert!(A.solve_gauss_seidel(&b, 1e-6, 10).is_some());
+        assert!(A.solve_jacobi(&b, 1e-6, 10).is_none());
+    }
+
+    #[test]
+    fn is_diagonally_dominant() {
+        let A = matrix![
+            3.0, -2.0,  1.0;
+            1.0, -3.0,  2.0;
+           -1.0,  2.0,  4.0;
+        ];
+        assert!(A.is_diagonally_dominant());
+
+        let A = matrix![
+           -2.0,  2.0,  1.0;
+            1.0,  3.0,  2.0;
+            1.0, -2.0,  0.0;
+        ];
+        assert!(!A.is_diagonally_dominant());
+    }
+}
@@ -1,24 +1,37 @@
 #![no_std]
 
 mod algebra;
+#[cfg(feature = "complex")]
+mod complex;
+mod decomp;
 mod fmt;
 mod index;
 mod iter;
+mod iterative;
 mod new;
 mod num;
 mod ops;
+mod optim;
+mod rotation;
 mod util;
 mod view;
 
 use core::{
-    mem::MaybeUninit,
-    ops::{Add, Div, Mul, Sub},
+    iter::Sum,
+    mem::{self, MaybeUninit},
+    ops::{Add, Div, Mul, Neg, Range, Sub},
     slice,
 };
 
+#[cfg(feature = "complex")]
+pub use complex::Complex;
 pub use index::MatrixIndex;
-pub use num::{Abs, Sqrt, Zero};
-pub use view::{Column, Row};
+pub use iter::{IntoIter, IterColumns, IterRows};
+pub use num::{
+    Abs, Acos, Asin, Atan2, Cos, Epsilon, FromUsize, LeBytes, Ln, One, Pi, Pod, Sin, Sqrt, Zero,
+};
+pub use optim::{levenberg_marquardt, newton_solve, numerical_gradient, numerical_jacobian};
+pub use view::{Column, Row, Transposed};
 
 #[doc(hidden)]
 pub use vectrix_macro as proc_macro;
@@ -48,6 +61,27 @@ impl<const M: usize, const N: usize, T> Matrix<M, N, T> {
         self.data.as_mut_ptr() as *mut T
     }
 
+    /// Returns `true`, as the elements of a matrix are always stored in
+    /// column-major order.
+    ///
+    /// The `Debug` output and [`as_slice`][Self::as_slice] reflect this
+    /// storage order, so consecutive elements run down the columns.
+    #[inline]
+    pub const fn is_column_major() -> bool {
+        true
+    }
+
+    /// Returns the offset of element `(row, col)` in the underlying storage,
+    /// i.e. `col * M + row`.
+    ///
+    /// This is the mapping used by the `(usize, usize)` index, which is useful
+    /// for computing offsets into [`as_slice`][Self::as_slice] or into a
+    /// buffer shared over FFI. No bounds checking is performed.
+    #[inline]
+    pub const fn flat_index(row: usize, col: usize) -> usize {
+        col * M + row
+    }
+
     /// Views the underlying data as a contiguous slice.
     #[inline]
     pub fn as_slice(&self) -> &[T] {
@@ -60,6 +94,102 @@ impl<const M: usize, const N: usize, T> Matrix<M, N, T> {
         unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), M * N) }
     }
 
+    /// Views a slice of exactly `M * N` elements in column-major order as a
+    /// matrix, without copying.
+    ///
+    /// Returns `None` if the length of `data` is not `M * N`.
+    #[inline]
+    pub fn from_slice_ref(data: &[T]) -> Option<&Self> {
+        if data.len() != M * N {
+            return None;
+        }
+        // SAFETY: `Matrix` is `repr(C)` around `[[T; M]; N]`, which has the same
+        // layout and alignment as `[T; M * N]`, and the length was checked above.
+        Some(unsafe { &*(data.as_ptr() as *const Self) })
+    }
+
+    /// Views a mutable slice of exactly `M * N` elements in column-major order
+    /// as a matrix, without copying.
+    ///
+    /// Returns `None` if the length of `data` is not `M * N`.
+    #[inline]
+    pub fn from_slice_mut(data: &mut [T]) -> Option<&mut Self> {
+        if data.len() != M * N {
+            return None;
+        }
+        // SAFETY: `Matrix` is `repr(C)` around `[[T; M]; N]`, which has the same
+        // layout and alignment as `[T; M * N]`, and the length was checked above.
+        Some(unsafe { &mut *(data.as_mut_ptr() as *mut Self) })
+    }
+
+    /// Views the underlying column-major storage as raw bytes in host
+    /// endianness.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        // SAFETY: `T: Pod` has no padding and `Matrix` is `repr(C)` around
+        // `[[T; M]; N]`, so all `size_of::<Self>()` bytes are initialized.
+        unsafe { slice::from_raw_parts(self.as_ptr() as *const u8, mem::size_of::<Self>()) }
+    }
+
+    /// Reinterprets raw bytes in host endianness as a reference to a matrix,
+    /// without copying.
+    ///
+    /// Returns `None` if `bytes` is not exactly `M * N * size_of::<T>()` long or
+    /// is not suitably aligned for `T`.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Option<&Self>
+    where
+        T: Pod,
+    {
+        if bytes.len() != mem::size_of::<Self>()
+            || bytes.as_ptr().align_offset(mem::align_of::<Self>()) != 0
+        {
+            return None;
+        }
+        // SAFETY: the length and alignment were checked above and every bit
+        // pattern is a valid `T: Pod`.
+        Some(unsafe { &*(bytes.as_ptr() as *const Self) })
+    }
+
+    /// Encodes the matrix as little-endian bytes in column-major order,
+    /// independent of the host endianness.
+    ///
+    /// The byte length `B` must equal `M * N * T::SIZE`, which is checked at
+    /// compile time.
+    pub fn to_le_bytes<const B: usize>(&self) -> [u8; B]
+    where
+        T: Copy + LeBytes,
+    {
+        const { assert!(B == M * N * T::SIZE, "byte length must be M * N * T::SIZE") };
+        let mut bytes = [0; B];
+        for (x, out) in self.iter().zip(bytes.chunks_exact_mut(T::SIZE)) {
+            x.write_le_bytes(out);
+        }
+        bytes
+    }
+
+    /// Decodes a matrix from little-endian bytes in column-major order, as
+    /// produced by [`to_le_bytes`][Self::to_le_bytes].
+    ///
+    /// The byte length `B` must equal `M * N * T::SIZE`, which is checked at
+    /// compile time.
+    pub fn from_le_bytes<const B: usize>(bytes: &[u8; B]) -> Self
+    where
+        T: LeBytes,
+    {
+        const { assert!(B == M * N * T::SIZE, "byte length must be M * N * T::SIZE") };
+        let mut m = Matrix::<M, N, MaybeUninit<T>>::uninit();
+        for (i, chunk) in bytes.chunks_exact(T::SIZE).enumerate() {
+            m[i] = MaybeUninit::new(T::read_le_bytes(chunk));
+        }
+        // SAFETY: `bytes` holds exactly `M * N` chunks, so every element was
+        // written above.
+        unsafe { m.assume_init() }
+    }
+
     /// Returns a reference to the `i`-th row of this matrix.
     #[inline]
     pub fn row(&self, i: usize) -> &Row<M, N, T> {
@@ -84,6 +214,48 @@ impl<const M: usize, const N: usize, T> Matrix<M, N, T> {
         Column::new_mut(&mut self.data[i])
     }
 
+    /// Copies the `i`-th row of this matrix into an owned row vector.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn row_vector(&self, i: usize) -> Matrix<1, N, T>
+    where
+        T: Copy,
+    {
+        assert!(
+            i < M,
+            "row index {} out of bounds for a {}x{} matrix",
+            i,
+            M,
+            N
+        );
+        Matrix::from_fn(|_, c| self.data[c][i])
+    }
+
+    /// Copies the `j`-th column of this matrix into an owned column vector.
+    ///
+    /// # Panics
+    ///
+    /// If `j` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn col_vector(&self, j: usize) -> Matrix<M, 1, T>
+    where
+        T: Copy,
+    {
+        assert!(
+            j < N,
+            "column index {} out of bounds for a {}x{} matrix",
+            j,
+            M,
+            N
+        );
+        Matrix::from_column_major_order([self.data[j]])
+    }
+
     /// Returns a reference to an element in the matrix or `None` if out of
     /// bounds.
     #[inline]
@@ -180,6 +352,193 @@ impl<const M: usize, const N: usize, T> Matrix<M, N, T> {
         }
     }
 
+    /// Circularly shift the rows of this matrix down by `shift`, wrapping
+    /// around at the end, like NumPy's `roll` along the first axis.
+    ///
+    /// Row `r` of the result is row `r - shift` (modulo `M`) of this matrix,
+    /// so a negative `shift` rolls rows up.
+    pub fn roll_rows(&self, shift: isize) -> Self
+    where
+        T: Copy,
+    {
+        if M == 0 {
+            return *self;
+        }
+        let s = shift.rem_euclid(M as isize) as usize;
+        Self::from_fn(|r, c| self[((r + M - s) % M, c)])
+    }
+
+    /// Circularly shift the columns of this matrix right by `shift`, wrapping
+    /// around at the end, like NumPy's `roll` along the second axis.
+    ///
+    /// Column `c` of the result is column `c - shift` (modulo `N`) of this
+    /// matrix, so a negative `shift` rolls columns left.
+    pub fn roll_columns(&self, shift: isize) -> Self
+    where
+        T: Copy,
+    {
+        if N == 0 {
+            return *self;
+        }
+        let s = shift.rem_euclid(N as isize) as usize;
+        Self::from_fn(|r, c| self[(r, (c + N - s) % N)])
+    }
+
+    /// Swap the two given elements of this matrix
+    ///
+    /// # Panics
+    ///
+    /// If either `(row, col)` index is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn swap_elements(&mut self, a: (usize, usize), b: (usize, usize)) {
+        assert!(
+            a.0 < M && a.1 < N && b.0 < M && b.1 < N,
+            "index out of bounds: swapping {:?} and {:?} in a {}x{} matrix",
+            a,
+            b,
+            M,
+            N
+        );
+        self.as_mut_slice()
+            .swap(Self::flat_index(a.0, a.1), Self::flat_index(b.0, b.1));
+    }
+
+    /// Copy the `R`-by-`C` block starting at `(row0, col0)` into an owned
+    /// matrix.
+    ///
+    /// # Panics
+    ///
+    /// If the block extends past the bounds of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stack_algebra::*;
+    /// let m = matrix![
+    ///     1, 2, 3;
+    ///     4, 5, 6;
+    ///     7, 8, 9;
+    /// ];
+    /// let minor: Matrix<2, 2, _> = m.submatrix(0, 0);
+    /// assert_eq!(minor, matrix![1, 2; 4, 5]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn submatrix<const R: usize, const C: usize>(
+        &self,
+        row0: usize,
+        col0: usize,
+    ) -> Matrix<R, C, T>
+    where
+        T: Copy,
+    {
+        assert!(
+            row0 + R <= M && col0 + C <= N,
+            "submatrix out of bounds: {}x{} block at ({}, {}) in a {}x{} matrix",
+            R,
+            C,
+            row0,
+            col0,
+            M,
+            N
+        );
+        Matrix::from_fn(|r, c| self[(row0 + r, col0 + c)])
+    }
+
+    /// Overwrite the `R`-by-`C` block starting at `(row0, col0)` with `block`.
+    ///
+    /// # Panics
+    ///
+    /// If the block extends past the bounds of this matrix.
+    #[inline]
+    #[track_caller]
+    pub fn set_submatrix<const R: usize, const C: usize>(
+        &mut self,
+        row0: usize,
+        col0: usize,
+        block: &Matrix<R, C, T>,
+    ) where
+        T: Copy,
+    {
+        assert!(
+            row0 + R <= M && col0 + C <= N,
+            "submatrix out of bounds: {}x{} block at ({}, {}) in a {}x{} matrix",
+            R,
+            C,
+            row0,
+            col0,
+            M,
+            N
+        );
+        for c in 0..C {
+            for r in 0..R {
+                self[(row0 + r, col0 + c)] = block[(r, c)];
+            }
+        }
+    }
+
+    /// Copy the block at the given runtime row and column ranges into an
+    /// owned `R`-by-`C` matrix.
+    ///
+    /// The size of the block is fixed by `R` and `C`, only its offset is
+    /// determined at runtime.
+    ///
+    /// # Panics
+    ///
+    /// If the lengths of the ranges are not `R` and `C`, or if the block
+    /// extends past the bounds of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stack_algebra::*;
+    /// let m = matrix![
+    ///     1, 2, 3;
+    ///     4, 5, 6;
+    ///     7, 8, 9;
+    /// ];
+    /// let s: Matrix<2, 2, _> = m.slice(1..3, 0..2);
+    /// assert_eq!(s, matrix![4, 5; 7, 8]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn slice<const R: usize, const C: usize>(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Matrix<R, C, T>
+    where
+        T: Copy,
+    {
+        assert_eq!(rows.len(), R, "row range does not match the output size");
+        assert_eq!(cols.len(), C, "column range does not match the output size");
+        self.submatrix(rows.start, cols.start)
+    }
+
+    /// Copy the block at the given runtime row and column ranges into `out`.
+    ///
+    /// This is [`slice`][Self::slice] with the output size taken from an
+    /// existing matrix, which avoids spelling out `R` and `C` at the call
+    /// site.
+    ///
+    /// # Panics
+    ///
+    /// If the lengths of the ranges are not `R` and `C`, or if the block
+    /// extends past the bounds of this matrix.
+    #[inline]
+    #[track_caller]
+    pub fn slice_into<const R: usize, const C: usize>(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+        out: &mut Matrix<R, C, T>,
+    ) where
+        T: Copy,
+    {
+        *out = self.slice(rows, cols);
+    }
+
     // /// Clone the current matrix.
     // #[inline]
     // pub fn clone(&self) -> Matrix<M, N, T>
@@ -212,6 +571,40 @@ impl<const M: usize, const N: usize, T> Matrix<M, N, T> {
         transpose
     }
 
+    /// Transpose of the current matrix, usable in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stack_algebra::*;
+    /// const A: Matrix<2, 3, i32> = matrix![1, 2, 3; 4, 5, 6];
+    /// const AT: Matrix<3, 2, i32> = A.transpose_const();
+    /// assert_eq!(AT, A.transpose());
+    /// ```
+    #[inline]
+    pub const fn transpose_const(&self) -> Matrix<N, M, T>
+    where
+        T: Copy,
+    {
+        let mut data = MaybeUninit::<[[T; N]; M]>::uninit();
+        let ptr = data.as_mut_ptr() as *mut T;
+        let mut c = 0;
+        while c < N {
+            let mut r = 0;
+            while r < M {
+                // SAFETY: element `(c, r)` of the `N`-by-`M` output lives at
+                // `r * N + c`, which is in bounds.
+                unsafe { ptr.add(r * N + c).write(self.data[c][r]) };
+                r += 1;
+            }
+            c += 1;
+        }
+        // SAFETY: every element was written in the loop above.
+        Matrix {
+            data: unsafe { data.assume_init() },
+        }
+    }
+
     /// Transpose of the current matrix.
     #[allow(non_snake_case)]
     #[inline]
@@ -222,6 +615,87 @@ impl<const M: usize, const N: usize, T> Matrix<M, N, T> {
         self.transpose()
     }
 
+    /// Negate every element of this matrix in place.
+    #[inline]
+    pub fn neg_assign(&mut self)
+    where
+        T: Copy + Neg<Output = T>,
+    {
+        for x in self.as_mut_slice() {
+            *x = -*x;
+        }
+    }
+
+    /// Replace every element of this matrix with its absolute value in place.
+    #[inline]
+    pub fn abs_assign(&mut self)
+    where
+        T: Copy + Abs,
+    {
+        for x in self.as_mut_slice() {
+            *x = x.abs();
+        }
+    }
+
+    /// Update every element of this matrix in place with the corresponding
+    /// element of `other`, by calling `f(&mut self[i], &other[i])` in
+    /// column-major order.
+    #[inline]
+    pub fn zip_assign<F>(&mut self, other: &Matrix<M, N, T>, mut f: F)
+    where
+        F: FnMut(&mut T, &T),
+    {
+        for (a, b) in self.iter_mut().zip(other.iter()) {
+            f(a, b);
+        }
+    }
+
+    /// Compute the element-wise (Hadamard) product of two matrices.
+    #[inline]
+    pub fn component_mul(&self, other: &Matrix<M, N, T>) -> Matrix<M, N, T>
+    where
+        T: Copy + Mul<Output = T>,
+    {
+        let mut m = *self;
+        m.zip_assign(other, |a, &b| *a = *a * b);
+        m
+    }
+
+    /// Compute the element-wise quotient of two matrices.
+    #[inline]
+    pub fn component_div(&self, other: &Matrix<M, N, T>) -> Matrix<M, N, T>
+    where
+        T: Copy + Div<Output = T>,
+    {
+        let mut m = *self;
+        m.zip_assign(other, |a, &b| *a = *a / b);
+        m
+    }
+
+    /// Returns a borrowed view of the transpose of this matrix.
+    ///
+    /// Unlike [`transpose`][Self::transpose] this does not copy any elements,
+    /// so products like `A.transposed() * x` avoid materializing `Aᵀ`.
+    #[inline]
+    pub fn transposed(&self) -> Transposed<'_, M, N, T> {
+        Transposed::new(self)
+    }
+
+    /// Compute the dot product of two vectors of the same shape.
+    ///
+    /// This works for both column and row vectors. For general matrices it
+    /// is the Frobenius inner product `Σᵢⱼ aᵢⱼ bᵢⱼ`.
+    pub fn dot(&self, other: &Self) -> T
+    where
+        T: Copy + Mul<Output = T> + Sum,
+    {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .map(|(&a, &b)| a * b)
+            .sum()
+    }
+
     /// Compute the Frobenius norm
     pub fn norm(&self) -> T
     where
@@ -245,110 +719,619 @@ impl<const M: usize, const N: usize, T> Matrix<M, N, T> {
         self / self.norm()
     }
 
-    // /// Returns an iterator over the rows in this matrix.
-    // #[inline]
-    // pub fn iter_rows(&self) -> IterRows<'_, T, M, N> {
-    //     IterRows::new(self)
-    // }
-
-    // /// Returns a mutable iterator over the rows in this matrix.
-    // #[inline]
-    // pub fn iter_rows_mut(&mut self) -> IterRowsMut<'_, T, M, N> {
-    //     IterRowsMut::new(self)
-    // }
-
-    // /// Returns an iterator over the columns in this matrix.
-    // #[inline]
-    // pub fn iter_columns(&self) -> IterColumns<'_, T, M, N> {
-    //     IterColumns::new(self)
-    // }
-
-    // /// Returns a mutable iterator over the columns in this matrix.
-    // #[inline]
-    // pub fn iter_columns_mut(&mut self) -> IterColumnsMut<'_, T, M, N> {
-    //     IterColumnsMut::new(self)
-    // }
+    /// Normalize the matrix to unit norm, or return `None` if its norm is zero.
+    ///
+    /// Unlike [`normalize`](Matrix::normalize) this never divides by zero, so
+    /// a zero vector doesn't silently turn into NaNs.
+    pub fn try_normalize(self) -> Option<Self>
+    where
+        T: Copy
+            + Zero
+            + PartialEq
+            + Abs
+            + Sqrt
+            + Add<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        let n = self.norm();
+        if n == T::zero() {
+            None
+        } else {
+            Some(self / n)
+        }
+    }
 
-    // /// Returns a matrix of the same size as self, with function `f` applied to
-    // /// each element in column-major order.
-    // #[inline]
-    // pub fn map<F, U>(self, f: F) -> Matrix<M, N, U>
-    // where
-    //     F: FnMut(T) -> U,
-    // {
-    //     // SAFETY: the iterator has the exact number of elements required.
-    //     unsafe { new::collect_unchecked(self.into_iter().map(f)) }
-    // }
+    /// Sum of all elements in the matrix.
+    ///
+    /// An empty matrix sums to zero.
+    pub fn sum(&self) -> T
+    where
+        T: Copy + Zero + Add<Output = T>,
+    {
+        self.as_slice().iter().fold(T::zero(), |acc, &x| acc + x)
+    }
 
-    // /// Returns the L1 norm of the matrix.
-    // ///
-    // /// Also known as *Manhattan Distance* or *Taxicab norm*. L1 Norm is the sum
-    // /// of the magnitudes of the vectors in a space.
-    // pub fn l1_norm(&self) -> T
-    // where
-    //     T: Copy + Ord + Abs + Zero + Sum<T>,
-    // {
-    //     (0..N)
-    //         .map(|i| self.data[i].iter().copied().map(Abs::abs).sum())
-    //         .max()
-    //         .unwrap_or_else(Zero::zero)
-    // }
-}
+    /// Sum each row across its columns, collapsing the column axis into an
+    /// `M`-by-1 column vector.
+    pub fn sum_rows(&self) -> Matrix<M, 1, T>
+    where
+        T: Copy + Zero + Add<Output = T>,
+    {
+        Matrix::from_fn(|r, _| (0..N).fold(T::zero(), |acc, c| acc + self[(r, c)]))
+    }
 
-// impl<const M: usize, const N: usize, T> Clone for Matrix<M, N, T>
-// where
-//     for<'a> &'a T,
-// {
-//     fn clone(&self) -> Self {
-//         // let mut clone = zeros!(M, N, T);
-//         let mut clone = unsafe { Matrix::<M, N, MaybeUninit<T>>::uninit().assume_init() };
-//         for c in 0..N {
-//             for r in 0..M {
-//                 clone[(r, c)] = &self[(r, c)];
-//             }
-//         }
-//         clone
-//     }
-// }
+    /// Sum each column down its rows, collapsing the row axis into a
+    /// 1-by-`N` row vector.
+    pub fn sum_cols(&self) -> Matrix<1, N, T>
+    where
+        T: Copy + Zero + Add<Output = T>,
+    {
+        Matrix::from_fn(|_, c| self.data[c].iter().fold(T::zero(), |acc, &x| acc + x))
+    }
 
-////////////////////////////////////////////////////////////////////////////////
-// Square matrix functions
-////////////////////////////////////////////////////////////////////////////////
-impl<const N: usize, T> Matrix<N, N, T> {
-    /// Compute the sum of diagonal elements
-    pub fn trace(&self) -> T
+    /// Product of all elements in the matrix.
+    ///
+    /// The product of an empty matrix is one.
+    pub fn product(&self) -> T
     where
-        T: Zero,
-        for<'a> &'a T: Add<&'a T, Output = T>,
+        T: Copy + One + Mul<Output = T>,
     {
-        let mut t = T::zero();
-        for i in 0..N {
-            t = &t + &self[(i, i)];
-        }
-        t
+        self.as_slice().iter().fold(T::one(), |acc, &x| acc * x)
     }
-}
 
-impl<T> Matrix<3, 1, T> {
-    pub fn cross(&self, other: &Self) -> Self
+    /// Smallest element in the matrix.
+    ///
+    /// Elements that are not comparable (e.g. `NaN`) never replace the
+    /// current minimum.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is empty.
+    #[track_caller]
+    pub fn min_element(&self) -> T
     where
-        for<'a> &'a T: Mul<&'a T, Output = T> + Sub<&'a T, Output = T>,
+        T: Copy + PartialOrd,
     {
-        let mut res = unsafe { Matrix::<3, 1, MaybeUninit<T>>::uninit().assume_init() };
-        res[0] = &(&self[1] * &other[2]) - &(&self[2] * &other[1]);
-        res[1] = &(&self[2] * &other[0]) - &(&self[0] * &other[2]);
-        res[2] = &(&self[0] * &other[1]) - &(&self[1] * &other[0]);
-        res
+        let (&first, rest) = self.as_slice().split_first().expect("matrix is empty");
+        rest.iter()
+            .fold(first, |acc, &x| if x < acc { x } else { acc })
     }
-}
 
-pub fn cross<T>(a: &Matrix<3, 1, T>, b: &Matrix<3, 1, T>) -> Matrix<3, 1, T>
-where
+    /// Largest element in the matrix.
+    ///
+    /// Elements that are not comparable (e.g. `NaN`) never replace the
+    /// current maximum.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is empty.
+    #[track_caller]
+    pub fn max_element(&self) -> T
+    where
+        T: Copy + PartialOrd,
+    {
+        let (&first, rest) = self.as_slice().split_first().expect("matrix is empty");
+        rest.iter()
+            .fold(first, |acc, &x| if x > acc { x } else { acc })
+    }
+
+    /// Stack the columns of this matrix into a single column vector (the
+    /// `vec` operator).
+    ///
+    /// As matrices are stored in column-major order this is a reshape. The
+    /// length `L` must equal `M * N`, which is checked at compile time.
+    #[inline]
+    pub fn vec<const L: usize>(&self) -> Vector<L, T>
+    where
+        T: Copy,
+    {
+        const { assert!(L == M * N, "vectorized length must be M * N") };
+        Vector::from_fn(|i, _| self[i])
+    }
+
+    /// Reshape a column vector into a matrix column by column, inverting
+    /// [`vec`][Self::vec].
+    ///
+    /// The length `L` must equal `M * N`, which is checked at compile time.
+    #[inline]
+    pub fn unvec<const L: usize>(v: &Vector<L, T>) -> Self
+    where
+        T: Copy,
+    {
+        const { assert!(L == M * N, "vectorized length must be M * N") };
+        Self::from_fn(|r, c| v[Self::flat_index(r, c)])
+    }
+
+    /// Compute the Kronecker product `self ⊗ other`.
+    ///
+    /// The result is the block matrix whose `(i, j)` block is
+    /// `self[(i, j)] * other`. Its dimensions `R = M * P` and `S = N * Q`
+    /// must be given explicitly and are checked at compile time.
+    pub fn kron<const P: usize, const Q: usize, const R: usize, const S: usize>(
+        &self,
+        other: &Matrix<P, Q, T>,
+    ) -> Matrix<R, S, T>
+    where
+        T: Copy + Mul<Output = T>,
+    {
+        const {
+            assert!(
+                R == M * P && S == N * Q,
+                "Kronecker product dimensions must be (M * P, N * Q)"
+            )
+        };
+        let mut k = Matrix::<R, S, MaybeUninit<T>>::uninit();
+        for j in 0..N {
+            for i in 0..M {
+                let a = self[(i, j)];
+                for c in 0..Q {
+                    for r in 0..P {
+                        k[(i * P + r, j * Q + c)] = MaybeUninit::new(a * other[(r, c)]);
+                    }
+                }
+            }
+        }
+        // SAFETY: the blocks `(i, j)` tile the whole `R`-by-`S` result, so every
+        // element was written above.
+        unsafe { k.assume_init() }
+    }
+
+    /// Compute the Kronecker product `I_P ⊗ self` with the `P`-by-`P` identity,
+    /// i.e. the block-diagonal matrix with `P` copies of `self`.
+    ///
+    /// This is cheaper than [`kron`][Self::kron] as the identity is never
+    /// formed. The dimensions `R = P * M` and `S = P * N` must be given
+    /// explicitly and are checked at compile time.
+    pub fn kron_with_identity_left<const P: usize, const R: usize, const S: usize>(
+        &self,
+    ) -> Matrix<R, S, T>
+    where
+        T: Copy + Zero,
+    {
+        const {
+            assert!(
+                R == P * M && S == P * N,
+                "Kronecker product dimensions must be (P * M, P * N)"
+            )
+        };
+        let mut k = Matrix::<R, S, T>::zeros();
+        for b in 0..P {
+            for c in 0..N {
+                for r in 0..M {
+                    k[(b * M + r, b * N + c)] = self[(r, c)];
+                }
+            }
+        }
+        k
+    }
+
+    /// Compute the Kronecker product `self ⊗ I_P` with the `P`-by-`P`
+    /// identity, i.e. the block matrix whose `(i, j)` block is
+    /// `self[(i, j)] * I_P`.
+    ///
+    /// This is cheaper than [`kron`][Self::kron] as the identity is never
+    /// formed. The dimensions `R = M * P` and `S = N * P` must be given
+    /// explicitly and are checked at compile time.
+    pub fn kron_with_identity_right<const P: usize, const R: usize, const S: usize>(
+        &self,
+    ) -> Matrix<R, S, T>
+    where
+        T: Copy + Zero,
+    {
+        const {
+            assert!(
+                R == M * P && S == N * P,
+                "Kronecker product dimensions must be (M * P, N * P)"
+            )
+        };
+        let mut k = Matrix::<R, S, T>::zeros();
+        for j in 0..N {
+            for i in 0..M {
+                for d in 0..P {
+                    k[(i * P + d, j * P + d)] = self[(i, j)];
+                }
+            }
+        }
+        k
+    }
+
+    /// Repeat the matrix `R` times vertically and `C` times horizontally, like
+    /// NumPy's `tile`.
+    ///
+    /// The dimensions `P = M * R` and `Q = N * C` of the result must be given
+    /// explicitly and are checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stack_algebra::*;
+    /// let m = matrix![1, 2];
+    /// let t = m.tile::<2, 2, 2, 4>();
+    /// assert_eq!(t, matrix![1, 2, 1, 2; 1, 2, 1, 2]);
+    /// ```
+    pub fn tile<const R: usize, const C: usize, const P: usize, const Q: usize>(
+        &self,
+    ) -> Matrix<P, Q, T>
+    where
+        T: Copy,
+    {
+        const {
+            assert!(
+                P == M * R && Q == N * C,
+                "tiled dimensions must be (M * R, N * C)"
+            )
+        };
+        Matrix::from_fn(|r, c| self[(r % M, c % N)])
+    }
+
+    /// Returns an iterator over the rows in this matrix.
+    #[inline]
+    pub fn iter_rows(&self) -> IterRows<'_, T, M, N> {
+        IterRows::new(self)
+    }
+
+    /// Returns an iterator over the columns in this matrix.
+    #[inline]
+    pub fn iter_columns(&self) -> IterColumns<'_, T, M, N> {
+        IterColumns::new(self)
+    }
+
+    /// Returns a matrix of the same size as self, with function `f` applied to
+    /// each element in column-major order.
+    #[inline]
+    pub fn map<F, U>(self, f: F) -> Matrix<M, N, U>
+    where
+        F: FnMut(T) -> U,
+    {
+        // SAFETY: the iterator has the exact number of elements required.
+        unsafe { new::collect_unchecked(self.into_iter().map(f)) }
+    }
+
+    /// Returns a matrix of the same size as self, with the fallible function
+    /// `f` applied to each element in column-major order.
+    ///
+    /// Stops at the first element for which `f` returns an error and returns
+    /// that error. Any elements already produced are dropped.
+    pub fn try_map<F, U, E>(&self, mut f: F) -> Result<Matrix<M, N, U>, E>
+    where
+        F: FnMut(&T) -> Result<U, E>,
+    {
+        let mut error = None;
+        let iter = self.iter().map_while(|x| match f(x) {
+            Ok(u) => Some(u),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        });
+        match new::collect(iter) {
+            Ok(matrix) => Ok(matrix),
+            // The iterator only ends early when `f` fails, so the error is set
+            Err(_) => Err(error.unwrap()),
+        }
+    }
+
+    // /// Returns the L1 norm of the matrix.
+    // ///
+    // /// Also known as *Manhattan Distance* or *Taxicab norm*. L1 Norm is the sum
+    // /// of the magnitudes of the vectors in a space.
+    // pub fn l1_norm(&self) -> T
+    // where
+    //     T: Copy + Ord + Abs + Zero + Sum<T>,
+    // {
+    //     (0..N)
+    //         .map(|i| self.data[i].iter().copied().map(Abs::abs).sum())
+    //         .max()
+    //         .unwrap_or_else(Zero::zero)
+    // }
+}
+
+// impl<const M: usize, const N: usize, T> Clone for Matrix<M, N, T>
+// where
+//     for<'a> &'a T,
+// {
+//     fn clone(&self) -> Self {
+//         // let mut clone = zeros!(M, N, T);
+//         let mut clone = unsafe { Matrix::<M, N, MaybeUninit<T>>::uninit().assume_init() };
+//         for c in 0..N {
+//             for r in 0..M {
+//                 clone[(r, c)] = &self[(r, c)];
+//             }
+//         }
+//         clone
+//     }
+// }
+
+////////////////////////////////////////////////////////////////////////////////
+// Square matrix functions
+////////////////////////////////////////////////////////////////////////////////
+impl<const N: usize, T> Matrix<N, N, T> {
+    /// Compute the sum of diagonal elements
+    pub fn trace(&self) -> T
+    where
+        T: Copy + Zero + Add<Output = T>,
+    {
+        let mut t = T::zero();
+        for i in 0..N {
+            t = t + self[(i, i)];
+        }
+        t
+    }
+
+    /// Compute the sum of diagonal elements using addition by reference.
+    ///
+    /// Equivalent to [`trace`][Self::trace] for element types that are not
+    /// `Copy` but can be added by reference.
+    pub fn trace_by_ref(&self) -> T
+    where
+        T: Zero,
+        for<'a> &'a T: Add<&'a T, Output = T>,
+    {
+        let mut t = T::zero();
+        for i in 0..N {
+            t = &t + &self[(i, i)];
+        }
+        t
+    }
+
+    /// Raise the matrix to the integer power `exp`, with `A⁰ = I`.
+    ///
+    /// Uses exponentiation by squaring, so only `O(log exp)` matrix
+    /// multiplications are performed.
+    pub fn pow(&self, mut exp: u32) -> Matrix<N, N, T>
+    where
+        T: Copy + Zero + One + Mul<Output = T> + Sum,
+    {
+        let mut result = Matrix::<N, N, T>::eye();
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base *= base;
+            }
+        }
+        result
+    }
+
+    /// Check whether the matrix is exactly symmetric, i.e. `A = Aᵀ`.
+    ///
+    /// For floating point matrices see
+    /// [`is_symmetric_tol`][Self::is_symmetric_tol].
+    pub fn is_symmetric(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        (0..N).all(|c| (0..c).all(|r| self[(r, c)] == self[(c, r)]))
+    }
+
+    /// Check whether the matrix is symmetric, treating elements that differ
+    /// from their transposed counterparts by at most `tol` as equal.
+    pub fn is_symmetric_tol(&self, tol: T) -> bool
+    where
+        T: Copy + Abs + PartialOrd + Sub<Output = T>,
+    {
+        (0..N).all(|c| (0..c).all(|r| (self[(r, c)] - self[(c, r)]).abs() <= tol))
+    }
+
+    /// Check whether every off-diagonal element is exactly zero.
+    ///
+    /// For floating point matrices see
+    /// [`is_diagonal_tol`][Self::is_diagonal_tol].
+    pub fn is_diagonal(&self) -> bool
+    where
+        T: Zero + PartialEq,
+    {
+        let zero = T::zero();
+        (0..N).all(|c| (0..N).all(|r| r == c || self[(r, c)] == zero))
+    }
+
+    /// Check whether every off-diagonal element is at most `tol` in
+    /// magnitude.
+    pub fn is_diagonal_tol(&self, tol: T) -> bool
+    where
+        T: Copy + Abs + PartialOrd,
+    {
+        (0..N).all(|c| (0..N).all(|r| r == c || self[(r, c)].abs() <= tol))
+    }
+
+    /// Check whether the matrix is orthogonal, i.e. every element of `AᵀA`
+    /// is within `tol` of the identity.
+    pub fn is_orthogonal(&self, tol: T) -> bool
+    where
+        T: Copy
+            + Zero
+            + One
+            + Abs
+            + PartialOrd
+            + Sum
+            + Add<Output = T>
+            + Mul<Output = T>
+            + Sub<Output = T>,
+    {
+        let prod = self.transpose() * self;
+        (0..N).all(|c| {
+            (0..N).all(|r| {
+                let id = if r == c { T::one() } else { T::zero() };
+                (prod[(r, c)] - id).abs() <= tol
+            })
+        })
+    }
+
+    /// Symmetric part of the matrix, `(A + Aᵀ) / 2`.
+    pub fn symmetrize(&self) -> Matrix<N, N, T>
+    where
+        T: Copy + One + Add<Output = T> + Div<Output = T>,
+    {
+        (*self + self.transpose()) / (T::one() + T::one())
+    }
+
+    /// Skew-symmetric part of the matrix, `(A - Aᵀ) / 2`.
+    pub fn antisymmetrize(&self) -> Matrix<N, N, T>
+    where
+        T: Copy + One + Add<Output = T> + Sub<Output = T> + Div<Output = T>,
+    {
+        (*self - self.transpose()) / (T::one() + T::one())
+    }
+
+    /// Volumetric (hydrostatic) part of the matrix, `(tr(A) / N) I`.
+    pub fn volumetric(&self) -> Matrix<N, N, T>
+    where
+        T: Copy + Zero + One + FromUsize + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        eye!(N, T) * (self.trace() / T::from_usize(N))
+    }
+
+    /// Deviatoric (trace-free) part of the matrix, `A - (tr(A) / N) I`.
+    ///
+    /// Together with [`volumetric`][Self::volumetric] this splits a stress
+    /// tensor into its shape-changing and volume-changing components.
+    pub fn deviatoric(&self) -> Matrix<N, N, T>
+    where
+        T: Copy
+            + Zero
+            + One
+            + FromUsize
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        *self - self.volumetric()
+    }
+
+    /// Compute the trace of the product `tr(A₀ A₁ ... Aₖ)` of a chain of
+    /// matrices.
+    ///
+    /// Only the product of all but the last matrix is formed; its trace with
+    /// the last matrix is `Σᵢⱼ prod[(i, j)] Aₖ[(j, i)]`, which saves the final
+    /// matrix multiplication. An empty chain is the identity, with trace `N`.
+    pub fn trace_of_chain(mats: &[&Matrix<N, N, T>]) -> T
+    where
+        T: Copy + Zero + One + Sum + Add<Output = T> + Mul<Output = T>,
+    {
+        match mats.split_last() {
+            None => (0..N).map(|_| T::one()).sum(),
+            Some((last, rest)) => {
+                let mut iter = rest.iter();
+                let prod = match iter.next() {
+                    Some(first) => iter.fold(**first, |acc, m| acc * *m),
+                    None => return (0..N).map(|i| last[(i, i)]).sum(),
+                };
+                (0..N)
+                    .flat_map(|i| (0..N).map(move |j| (i, j)))
+                    .map(|(i, j)| prod[(i, j)] * last[(j, i)])
+                    .sum()
+            }
+        }
+    }
+}
+
+impl<T> Matrix<3, 1, T> {
+    pub fn cross(&self, other: &Self) -> Self
+    where
+        for<'a> &'a T: Mul<&'a T, Output = T> + Sub<&'a T, Output = T>,
+    {
+        let mut res = unsafe { Matrix::<3, 1, MaybeUninit<T>>::uninit().assume_init() };
+        res[0] = &(&self[1] * &other[2]) - &(&self[2] * &other[1]);
+        res[1] = &(&self[2] * &other[0]) - &(&self[0] * &other[2]);
+        res[2] = &(&self[0] * &other[1]) - &(&self[1] * &other[0]);
+        res
+    }
+}
+
+impl<const M: usize, T> Matrix<M, 1, T> {
+    /// Compute the Euclidean length of the vector.
+    ///
+    /// This is the same as [`norm`](Matrix::norm), under a name that reads
+    /// better for vectors.
+    pub fn length(&self) -> T
+    where
+        T: Copy + Zero + Abs + Sqrt + Add<Output = T> + Mul<Output = T>,
+    {
+        self.norm()
+    }
+
+    /// Compute the (unnormalized) autocorrelation of the signal for lags
+    /// `0..M`, i.e. `r[k] = Σᵢ x[i] x[i + k]`.
+    ///
+    /// Together with [`toeplitz`](Matrix::toeplitz) this gives the
+    /// Yule-Walker equations for linear prediction. Divide by `M` for the
+    /// biased estimator.
+    pub fn autocorrelation(&self) -> Matrix<M, 1, T>
+    where
+        T: Copy + Mul<Output = T> + Sum,
+    {
+        let x = self.as_slice();
+        Matrix::from_fn(|k, _| x.iter().zip(&x[k..]).map(|(&a, &b)| a * b).sum())
+    }
+
+    /// Compute the outer product `self * other`, the rank-1 matrix whose
+    /// `(i, j)` entry is `self[i] * other[j]`.
+    pub fn outer<const N: usize>(&self, other: &Matrix<1, N, T>) -> Matrix<M, N, T>
+    where
+        T: Copy + Mul<Output = T>,
+    {
+        Matrix::from_fn(|i, j| self[i] * other[j])
+    }
+}
+
+pub fn cross<T>(a: &Matrix<3, 1, T>, b: &Matrix<3, 1, T>) -> Matrix<3, 1, T>
+where
     for<'a> &'a T: Mul<&'a T, Output = T> + Sub<&'a T, Output = T>,
 {
     a.cross(b)
 }
 
+/// Concatenate two matrices side by side, `[a b]`.
+///
+/// The output width `N` must equal `N1 + N2`, which is checked at compile
+/// time. It can usually be inferred from the context.
+///
+/// # Examples
+///
+/// ```
+/// # use stack_algebra::*;
+/// let m: Matrix<2, 3, _> = hstack(&matrix![1, 2; 4, 5], &matrix![3; 6]);
+/// assert_eq!(m, matrix![1, 2, 3; 4, 5, 6]);
+/// ```
+pub fn hstack<const M: usize, const N1: usize, const N2: usize, const N: usize, T>(
+    a: &Matrix<M, N1, T>,
+    b: &Matrix<M, N2, T>,
+) -> Matrix<M, N, T>
+where
+    T: Copy,
+{
+    const { assert!(N == N1 + N2, "output width must be N1 + N2") };
+    Matrix::from_fn(|r, c| if c < N1 { a[(r, c)] } else { b[(r, c - N1)] })
+}
+
+/// Concatenate two matrices on top of each other, `[a; b]`.
+///
+/// The output height `M` must equal `M1 + M2`, which is checked at compile
+/// time. It can usually be inferred from the context.
+///
+/// # Examples
+///
+/// ```
+/// # use stack_algebra::*;
+/// let m: Matrix<3, 2, _> = vstack(&matrix![1, 2; 3, 4], &matrix![5, 6]);
+/// assert_eq!(m, matrix![1, 2; 3, 4; 5, 6]);
+/// ```
+pub fn vstack<const M1: usize, const M2: usize, const M: usize, const N: usize, T>(
+    a: &Matrix<M1, N, T>,
+    b: &Matrix<M2, N, T>,
+) -> Matrix<M, N, T>
+where
+    T: Copy,
+{
+    const { assert!(M == M1 + M2, "output height must be M1 + M2") };
+    Matrix::from_fn(|r, c| if r < M1 { a[(r, c)] } else { b[(r - M1, c)] })
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // 3D/4D Vector Type Conversion to Tuple
 ////////////////////////////////////////////////////////////////////////////////
@@ -401,7 +1384,7 @@ impl<T: Copy> From<Matrix<1, 4, T>> for (T, T, T, T) {
     }
 }
 
-// #[cfg(test)]
+#[cfg(any(test, feature = "approx"))]
 impl<const M: usize, const N: usize, T: approx::AbsDiffEq> approx::AbsDiffEq for Matrix<M, N, T>
 where
     T::Epsilon: Copy,
@@ -425,7 +1408,7 @@ where
     }
 }
 
-// #[cfg(test)]
+#[cfg(any(test, feature = "approx"))]
 impl<const M: usize, const N: usize, T: approx::RelativeEq> approx::RelativeEq for Matrix<M, N, T>
 where
     T::Epsilon: Copy,
@@ -453,6 +1436,29 @@ where
     }
 }
 
+#[cfg(any(test, feature = "approx"))]
+impl<const M: usize, const N: usize, T: approx::UlpsEq> approx::UlpsEq for Matrix<M, N, T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        let mut eq = true;
+        for j in 0..N {
+            for i in 0..M {
+                eq = eq && T::ulps_eq(&self[(i, j)], &other[(i, j)], epsilon, max_ulps);
+                if !eq {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
 /// A matrix with one row and `N` columns.
 pub type RowVector<const N: usize, T> = Matrix<1, N, T>;
 
@@ -464,6 +1470,304 @@ mod tests {
     use super::*;
     use approx::assert_relative_eq;
 
+    #[test]
+    fn from_slice_ref() {
+        let buf = [0, 1, 2, 3, 4, 5, 6, 7];
+        let m = Matrix::<2, 3, i32>::from_slice_ref(&buf[1..7]).unwrap();
+        assert_eq!(m, &matrix![1, 3, 5; 2, 4, 6]);
+
+        assert!(Matrix::<2, 3, i32>::from_slice_ref(&buf).is_none());
+        assert!(Matrix::<2, 3, i32>::from_slice_ref(&buf[..5]).is_none());
+    }
+
+    #[test]
+    fn as_bytes() {
+        let m = matrix![
+            1.0_f32, -2.5, 3.0;
+            0.0, 1e-3, 7.0;
+        ];
+        let bytes = m.as_bytes();
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(&bytes[12..16], &1e-3_f32.to_ne_bytes());
+        assert_eq!(Matrix::<2, 3, f32>::from_bytes(bytes), Some(&m));
+
+        // Wrong length
+        assert_eq!(Matrix::<2, 3, f32>::from_bytes(&bytes[..20]), None);
+        assert_eq!(Matrix::<3, 3, f32>::from_bytes(bytes), None);
+
+        // Misaligned
+        #[repr(C, align(4))]
+        struct Aligned([u8; 28]);
+        let mut buf = Aligned([0; 28]);
+        buf.0[1..25].copy_from_slice(bytes);
+        assert_eq!(Matrix::<2, 3, f32>::from_bytes(&buf.0[1..25]), None);
+        buf.0[4..28].copy_from_slice(bytes);
+        assert_eq!(Matrix::<2, 3, f32>::from_bytes(&buf.0[4..28]), Some(&m));
+    }
+
+    #[test]
+    fn to_le_bytes() {
+        let m = matrix![
+            0x0102_u16, 0x0304;
+            0x0506, 0x0708;
+        ];
+        let bytes: [u8; 8] = m.to_le_bytes();
+        assert_eq!(bytes, [0x02, 0x01, 0x06, 0x05, 0x04, 0x03, 0x08, 0x07]);
+        assert_eq!(Matrix::<2, 2, u16>::from_le_bytes(&bytes), m);
+
+        let m = matrix![1.0_f32, -2.0];
+        let bytes: [u8; 8] = m.to_le_bytes();
+        assert_eq!(bytes, [0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0xc0]);
+        assert_eq!(Matrix::<1, 2, f32>::from_le_bytes(&bytes), m);
+    }
+
+    #[test]
+    fn from_slice_mut() {
+        let mut buf = [0.0; 8];
+        let m = Matrix::<3, 2, f32>::from_slice_mut(&mut buf[1..7]).unwrap();
+        m[(1, 1)] = 5.0;
+        *m *= 2.0;
+        assert_eq!(buf, [0.0, 0.0, 0.0, 0.0, 0.0, 10.0, 0.0, 0.0]);
+
+        assert!(Matrix::<3, 2, f32>::from_slice_mut(&mut buf[1..]).is_none());
+    }
+
+    #[test]
+    fn flat_index() {
+        assert!(Matrix::<2, 3, i32>::is_column_major());
+
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+        assert_eq!(m.as_slice(), &[1, 4, 2, 5, 3, 6]);
+        for r in 0..2 {
+            for c in 0..3 {
+                assert_eq!(
+                    m.as_slice()[Matrix::<2, 3, i32>::flat_index(r, c)],
+                    m[(r, c)]
+                );
+            }
+        }
+        assert_eq!(Matrix::<2, 3, i32>::flat_index(1, 2), 5);
+    }
+
+    #[test]
+    fn iter_rows() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+        let mut rows = m.iter_rows();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows.next().unwrap(), &[1, 2, 3]);
+        assert_eq!(rows.next().unwrap(), &[4, 5, 6]);
+        assert!(rows.next().is_none());
+    }
+    #[test]
+    fn iter_columns() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+        let cols: [&[i32]; 3] = [&[1, 4], &[2, 5], &[3, 6]];
+        assert_eq!(m.iter_columns().len(), 3);
+        for (col, exp) in m.iter_columns().zip(cols) {
+            assert_eq!(col, exp);
+        }
+        assert_eq!(m.iter_columns().next_back().unwrap(), &[3, 6]);
+    }
+    #[test]
+    fn sum_matrices() {
+        let ms = [
+            matrix![1, 2; 3, 4],
+            matrix![5, 6; 7, 8],
+            matrix![-1, 0; 0, -1],
+        ];
+        let s: Matrix<2, 2, i32> = ms.into_iter().sum();
+        assert_eq!(s, matrix![5, 8; 10, 11]);
+
+        let s: Matrix<2, 3, f64> = core::iter::empty().sum();
+        assert_eq!(s, Matrix::zeros());
+    }
+    #[test]
+    fn map() {
+        let m = matrix![
+            1.5_f32, -2.7, 3.0;
+            0.2, 5.9, -6.1;
+        ];
+        let r: Matrix<2, 3, i32> = m.map(|x| x as i32);
+        assert_eq!(r, matrix![1, -2, 3; 0, 5, -6]);
+        assert_eq!(
+            m.map(|x| x > 0.0),
+            matrix![true, false, true; true, true, false]
+        );
+
+        // Applied in column-major order
+        let mut order = [0.0; 6];
+        let mut i = 0;
+        let _ = m.map(|x| {
+            order[i] = x;
+            i += 1;
+        });
+        assert_eq!(&order, m.as_slice());
+
+        // Every produced element is dropped exactly once, and the input elements
+        // are consumed by `f`
+        extern crate std;
+        use std::rc::Rc;
+        let rc = Rc::new(());
+        let m = matrix![1, 2; 3, 4].map(|x| (x, Rc::clone(&rc)));
+        assert_eq!(Rc::strong_count(&rc), 5);
+        let n = m.map(|(x, r)| {
+            drop(r);
+            [x; 2]
+        });
+        assert_eq!(Rc::strong_count(&rc), 1);
+        assert_eq!(n[(1, 0)], [3, 3]);
+
+        // A partially consumed `IntoIter` drops the remaining elements
+        let mut iter = matrix![1, 2; 3, 4].map(|_| Rc::clone(&rc)).into_iter();
+        let first = iter.next();
+        drop(iter);
+        assert_eq!(Rc::strong_count(&rc), 2);
+        drop(first);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+    #[test]
+    fn try_map() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+        let r: Result<Matrix<2, 3, u8>, _> = m.try_map(|&x| u8::try_from(x * 40));
+        assert_eq!(r, Ok(matrix![40, 80, 120; 160, 200, 240]));
+
+        // 6 * 51 overflows a `u8`, which is reported as the error
+        let r = m.try_map(|&x| u8::try_from(x * 51).map_err(|_| x));
+        assert_eq!(r, Err(6));
+
+        // Elements produced before the failure (1, 4 and 2 in column-major
+        // order) are dropped
+        struct Counted<'a>(&'a core::cell::Cell<usize>);
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+        let drops = core::cell::Cell::new(0);
+        let r = m.try_map(|&x| if x < 5 { Ok(Counted(&drops)) } else { Err(x) });
+        assert!(matches!(r, Err(5)));
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn kron_with_identity() {
+        let a = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+        ];
+        let i = Matrix::<3, 3, f64>::eye();
+
+        let k: Matrix<6, 9, f64> = a.kron_with_identity_left::<3, 6, 9>();
+        let e: Matrix<6, 9, f64> = i.kron(&a);
+        assert_eq!(k, e);
+
+        let k: Matrix<6, 9, f64> = a.kron_with_identity_right::<3, 6, 9>();
+        let e: Matrix<6, 9, f64> = a.kron(&i);
+        assert_eq!(k, e);
+    }
+
+    #[test]
+    fn vec() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+        let v: Vector<6, i32> = m.vec();
+        assert_eq!(v, vector![1; 4; 2; 5; 3; 6]);
+        assert_eq!(Matrix::<2, 3, i32>::unvec(&v), m);
+
+        // Reshaping into other dimensions fills column by column
+        assert_eq!(Matrix::<3, 2, i32>::unvec(&v), matrix![1, 5; 4, 3; 2, 6]);
+        assert_eq!(Matrix::<3, 2, i32>::unvec(&v).vec(), v);
+    }
+
+    #[test]
+    fn outer() {
+        let a = vector![1.0; 2.0; 3.0];
+        let b = matrix![4.0, -5.0];
+        let m = a.outer(&b);
+        assert_eq!(m[(0, 0)], 4.0);
+        assert_eq!(m[(2, 1)], -15.0);
+        assert_eq!(m, matrix![4.0, -5.0; 8.0, -10.0; 12.0, -15.0]);
+        assert_eq!(m, a * b);
+    }
+
+    #[test]
+    fn trace_of_chain() {
+        let a = matrix![
+            1.0, 2.0, 0.0;
+            0.0, 1.0, 3.0;
+            4.0, 0.0, 1.0;
+        ];
+        let b = matrix![
+            2.0, 0.0, 1.0;
+            1.0, 1.0, 0.0;
+            0.0, 3.0, 1.0;
+        ];
+        let c = matrix![
+            1.0, 1.0, 1.0;
+            0.0, 2.0, 0.0;
+            1.0, 0.0, 5.0;
+        ];
+        assert_eq!(Matrix::trace_of_chain(&[&a, &b, &c]), (a * b * c).trace());
+        assert_eq!(Matrix::trace_of_chain(&[&a, &b]), (a * b).trace());
+        assert_eq!(Matrix::trace_of_chain(&[&c]), c.trace());
+        assert_eq!(Matrix::<3, 3, f64>::trace_of_chain(&[]), 3.0);
+    }
+
+    #[test]
+    fn tile() {
+        let m = matrix![
+            1, 2;
+            3, 4;
+        ];
+        let t = m.tile::<2, 2, 4, 4>();
+        let exp = matrix![
+            1, 2, 1, 2;
+            3, 4, 3, 4;
+            1, 2, 1, 2;
+            3, 4, 3, 4;
+        ];
+        assert_eq!(t, exp);
+        assert_eq!(
+            m.tile::<1, 3, 2, 6>(),
+            matrix![1, 2, 1, 2, 1, 2; 3, 4, 3, 4, 3, 4]
+        );
+        assert_eq!(m.tile::<1, 1, 2, 2>(), m);
+    }
+
+    #[test]
+    fn kron() {
+        let a = matrix![
+            1.0, 2.0;
+            3.0, 4.0;
+        ];
+        let b = matrix![
+            0.0, 5.0, 1.0;
+            6.0, 7.0, 0.0;
+        ];
+        let k: Matrix<4, 6, f64> = a.kron(&b);
+        let e = matrix![
+             0.0,  5.0, 1.0,  0.0, 10.0, 2.0;
+             6.0,  7.0, 0.0, 12.0, 14.0, 0.0;
+             0.0, 15.0, 3.0,  0.0, 20.0, 4.0;
+            18.0, 21.0, 0.0, 24.0, 28.0, 0.0;
+        ];
+        assert_eq!(k, e);
+    }
+
     #[test]
     fn create() {
         let m = matrix![
@@ -533,6 +1837,232 @@ mod tests {
         assert_eq!(m, exp);
     }
     #[test]
+    fn neg_assign() {
+        let mut m = matrix![
+            1.0, -2.0;
+            0.0,  4.5;
+        ];
+        m.neg_assign();
+        assert_eq!(m, matrix![-1.0, 2.0; 0.0, -4.5]);
+    }
+    #[test]
+    fn abs_assign() {
+        let mut m = matrix![
+            -1, 2, -3;
+             4, 0, -6;
+        ];
+        m.abs_assign();
+        assert_eq!(m, matrix![1, 2, 3; 4, 0, 6]);
+    }
+    #[test]
+    fn zip_assign() {
+        let mut a = matrix![
+            1, 5, 3;
+            7, 2, 0;
+        ];
+        let b = matrix![
+            4, 4, 4;
+            1, 9, 0;
+        ];
+        a.zip_assign(&b, |x, y| *x = (*x).max(*y));
+        assert_eq!(a, matrix![4, 5, 4; 7, 9, 0]);
+
+        let mut c = matrix![1.0, 2.0; 3.0, 4.0];
+        let d = matrix![0.5, 0.5; 1.0, 2.0];
+        c.zip_assign(&d, |x, y| *x += y);
+        assert_eq!(c, matrix![1.5, 2.5; 4.0, 6.0]);
+    }
+    #[test]
+    fn component_mul() {
+        let a = matrix![
+            1.0, 2.0;
+            3.0, 4.0;
+        ];
+        let b = matrix![
+            5.0, 6.0;
+            7.0, 8.0;
+        ];
+        assert_eq!(a.component_mul(&b), matrix![5.0, 12.0; 21.0, 32.0]);
+        assert_ne!(a.component_mul(&b), a * b);
+        assert_eq!(a.component_div(&b).component_mul(&b), a);
+        assert_eq!(b.component_div(&a), matrix![5.0, 3.0; 7.0 / 3.0, 2.0]);
+    }
+    #[test]
+    fn roll() {
+        let m = matrix![
+            11, 12, 13;
+            21, 22, 23;
+            31, 32, 33;
+        ];
+        let down = matrix![
+            31, 32, 33;
+            11, 12, 13;
+            21, 22, 23;
+        ];
+        assert_eq!(m.roll_rows(1), down);
+        assert_eq!(m.roll_rows(-2), down);
+        assert_eq!(m.roll_rows(7), down);
+        assert_eq!(m.roll_rows(0), m);
+        assert_eq!(m.roll_rows(-1), matrix![21, 22, 23; 31, 32, 33; 11, 12, 13]);
+
+        let right = matrix![
+            13, 11, 12;
+            23, 21, 22;
+            33, 31, 32;
+        ];
+        assert_eq!(m.roll_columns(1), right);
+        assert_eq!(m.roll_columns(-5), right);
+        assert_eq!(m.roll_columns(isize::MIN), right);
+        assert_eq!(
+            m.roll_columns(-1),
+            matrix![12, 13, 11; 22, 23, 21; 32, 33, 31]
+        );
+    }
+    #[test]
+    fn swap_elements() {
+        let mut m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+        m.swap_elements((0, 2), (1, 0));
+        assert_eq!(m, matrix![1, 2, 4; 3, 5, 6]);
+        m.swap_elements((1, 1), (1, 1));
+        assert_eq!(m, matrix![1, 2, 4; 3, 5, 6]);
+    }
+    #[test]
+    fn row_col_vector() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+        let r = m.row_vector(1);
+        assert_eq!(r, matrix![4, 5, 6]);
+        for c in 0..3 {
+            assert_eq!(r[c], m[(1, c)]);
+        }
+        let c = m.col_vector(2);
+        assert_eq!(c, vector![3; 6]);
+        for r in 0..2 {
+            assert_eq!(c[r], m[(r, 2)]);
+        }
+    }
+    #[test]
+    #[should_panic(expected = "row index 2 out of bounds")]
+    fn row_vector_out_of_bounds() {
+        matrix![1, 2; 3, 4].row_vector(2);
+    }
+    #[test]
+    #[should_panic(expected = "column index 3 out of bounds")]
+    fn col_vector_out_of_bounds() {
+        matrix![1, 2; 3, 4].col_vector(3);
+    }
+    #[test]
+    fn submatrix() {
+        let m = matrix![
+             1,  2,  3,  4;
+             5,  6,  7,  8;
+             9, 10, 11, 12;
+            13, 14, 15, 16;
+        ];
+        let s: Matrix<2, 2, _> = m.submatrix(1, 2);
+        assert_eq!(s, matrix![7, 8; 11, 12]);
+        let s: Matrix<2, 2, _> = m.submatrix(2, 2);
+        assert_eq!(s, matrix![11, 12; 15, 16]);
+        let s: Matrix<4, 4, _> = m.submatrix(0, 0);
+        assert_eq!(s, m);
+    }
+    #[test]
+    #[should_panic(expected = "submatrix out of bounds")]
+    fn submatrix_out_of_bounds() {
+        let m = eye!(4, i32);
+        let _: Matrix<2, 2, _> = m.submatrix(3, 0);
+    }
+    #[test]
+    fn set_submatrix() {
+        let mut m = Matrix::<4, 4, i32>::zeros();
+        m.set_submatrix(1, 2, &matrix![1, 2; 3, 4]);
+        let exp = matrix![
+            0, 0, 0, 0;
+            0, 0, 1, 2;
+            0, 0, 3, 4;
+            0, 0, 0, 0;
+        ];
+        assert_eq!(m, exp);
+        let s: Matrix<2, 2, _> = m.submatrix(1, 2);
+        assert_eq!(s, matrix![1, 2; 3, 4]);
+    }
+    #[test]
+    #[should_panic(expected = "submatrix out of bounds")]
+    fn set_submatrix_out_of_bounds() {
+        let mut m = Matrix::<4, 4, i32>::zeros();
+        m.set_submatrix(0, 3, &matrix![1, 2; 3, 4]);
+    }
+    #[test]
+    fn slice() {
+        let m = matrix![
+            1, 2, 3, 4;
+            5, 6, 7, 8;
+            9, 10, 11, 12;
+        ];
+        let (r, c) = (1, 2);
+        let s: Matrix<2, 2, _> = m.slice(r..r + 2, c..c + 2);
+        assert_eq!(s, matrix![7, 8; 11, 12]);
+        let s: Matrix<1, 4, _> = m.slice(0..1, 0..4);
+        assert_eq!(s, matrix![1, 2, 3, 4]);
+        let s: Matrix<0, 0, _> = m.slice(3..3, 4..4);
+        assert_eq!(s, Matrix::<0, 0, i32>::zeros());
+    }
+    #[test]
+    fn slice_into() {
+        let m = matrix![
+            1, 2, 3, 4, 5;
+            6, 7, 8, 9, 10;
+            11, 12, 13, 14, 15;
+        ];
+        let mut out = Matrix::<2, 3, i32>::zeros();
+        m.slice_into(1..3, 2..5, &mut out);
+        assert_eq!(out, matrix![8, 9, 10; 13, 14, 15]);
+        assert_eq!(out.as_slice(), &[8, 13, 9, 14, 10, 15]);
+
+        let mut out = Matrix::<3, 1, i32>::zeros();
+        m.slice_into(0..3, 4..5, &mut out);
+        assert_eq!(out, vector![5; 10; 15]);
+    }
+    #[test]
+    #[should_panic(expected = "row range does not match the output size")]
+    fn slice_into_mismatched_range() {
+        let mut out = Matrix::<2, 2, i32>::zeros();
+        eye!(3, i32).slice_into(0..3, 0..2, &mut out);
+    }
+    #[test]
+    #[should_panic(expected = "column range does not match the output size")]
+    fn slice_into_mismatched_column_range() {
+        let mut out = Matrix::<2, 2, i32>::zeros();
+        eye!(3, i32).slice_into(1..3, 0..3, &mut out);
+    }
+    #[test]
+    #[should_panic(expected = "submatrix out of bounds")]
+    fn slice_out_of_bounds() {
+        let m = eye!(3, i32);
+        let _: Matrix<2, 2, _> = m.slice(2..4, 0..2);
+    }
+    #[test]
+    #[should_panic(expected = "row range does not match the output size")]
+    fn slice_mismatched_range() {
+        let m = eye!(3, i32);
+        let _: Matrix<2, 2, _> = m.slice(0..3, 0..2);
+    }
+    #[test]
+    #[should_panic]
+    fn swap_elements_out_of_bounds() {
+        // (2, 0) would alias (0, 1) if the row were not checked
+        let mut m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+        m.swap_elements((0, 0), (2, 0));
+    }
+    #[test]
     fn transpose() {
         let m = matrix![
             1.0, 2.0, 3.0;
@@ -562,6 +2092,185 @@ mod tests {
         assert_relative_eq!(m.norm(), 7.0710678, max_relative = 1e-6);
     }
 
+    #[test]
+    fn reductions() {
+        let m = matrix![
+            3, -1, 4;
+            1, -5, 9;
+        ];
+        assert_eq!(m.sum(), 11);
+        assert_eq!(m.product(), 540);
+        assert_eq!(m.min_element(), -5);
+        assert_eq!(m.max_element(), 9);
+
+        // `Ord::min` and `Ord::max` still compare whole matrices
+        let n = matrix![0, 0, 0; 0, 0, 0];
+        assert_eq!(m.min(n), n);
+        assert_eq!(m.max(n), m);
+
+        let e = Matrix::<0, 3, i32>::zeros();
+        assert_eq!(e.sum(), 0);
+        assert_eq!(e.product(), 1);
+    }
+    #[test]
+    fn sum_rows_cols() {
+        let m = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+        assert_eq!(m.sum_rows(), vector![6; 15]);
+        assert_eq!(m.sum_cols(), vector![5, 7, 9]);
+        assert_eq!(m.sum_rows().sum(), m.sum());
+        assert_eq!(m.sum_cols().sum(), m.sum());
+    }
+    #[test]
+    #[should_panic]
+    fn min_element_empty() {
+        Matrix::<2, 0, i32>::zeros().min_element();
+    }
+
+    #[test]
+    fn hstack() {
+        let a = matrix![1, 2; 3, 4];
+        let b = matrix![5, 6; 7, 8];
+        let m: Matrix<2, 4, _> = super::hstack(&a, &b);
+        assert_eq!(m, matrix![1, 2, 5, 6; 3, 4, 7, 8]);
+        assert_eq!(m.as_slice(), &[1, 3, 2, 4, 5, 7, 6, 8]);
+    }
+
+    #[test]
+    fn vstack() {
+        let a = matrix![1, 2; 3, 4];
+        let b = matrix![5, 6; 7, 8];
+        let m: Matrix<4, 2, _> = super::vstack(&a, &b);
+        assert_eq!(m, matrix![1, 2; 3, 4; 5, 6; 7, 8]);
+        assert_eq!(m.as_slice(), &[1, 3, 5, 7, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn transpose_const() {
+        const A: Matrix<2, 3, i32> = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+        const AT: Matrix<3, 2, i32> = A.transpose_const();
+        assert_eq!(AT, matrix![1, 4; 2, 5; 3, 6]);
+        assert_eq!(AT, A.transpose());
+
+        const E: Matrix<0, 2, f64> = Matrix::from_column_major_order([[], []]);
+        assert_eq!(
+            E.transpose_const(),
+            Matrix::<2, 0, f64>::from_column_major_order([])
+        );
+    }
+
+    #[test]
+    fn ulps_eq() {
+        use approx::{assert_ulps_eq, assert_ulps_ne};
+
+        let a: Matrix<2, 2, f32> = matrix![1.0, 2.0; 3.0, 4.0];
+        let mut b = a;
+        b[(1, 0)] = f32::from_bits(3.0_f32.to_bits() + 2);
+        assert_ulps_eq!(a, b);
+        assert_ulps_ne!(a, b, max_ulps = 1);
+
+        b[(1, 0)] = 3.001;
+        assert_ulps_ne!(a, b);
+    }
+
+    #[test]
+    fn length() {
+        let v = vector![3.0; 4.0];
+        assert_eq!(v.length(), 5.0);
+        assert_eq!(v.length(), v.norm());
+    }
+
+    #[test]
+    fn try_normalize() {
+        let v = vector![3.0; 0.0; 4.0].try_normalize().unwrap();
+        assert_eq!(v, vector![0.6; 0.0; 0.8]);
+        assert!((v.length() - 1.0).abs() < 1e-6);
+
+        assert_eq!(Vector::<3, f64>::zeros().try_normalize(), None);
+        assert!(Vector::<3, f64>::zeros().normalize()[0].is_nan());
+    }
+
+    #[test]
+    fn autocorrelation() {
+        // A signal with period 2
+        let x = vector![1; -1; 1; -1; 1; -1];
+        assert_eq!(x.autocorrelation(), vector![6; -5; 4; -3; 2; -1]);
+
+        let x = vector![1.0; 2.0; 3.0];
+        let r = x.autocorrelation();
+        assert_eq!(r, vector![14.0; 8.0; 3.0]);
+        assert_eq!(r[0], x.dot(&x));
+
+        // The autocorrelation matrix is symmetric Toeplitz
+        let r = Matrix::toeplitz(&r, &r.transpose());
+        assert_eq!(r, r.transpose());
+    }
+
+    #[test]
+    fn is_symmetric() {
+        let a = matrix![
+            1, 2, 3;
+            2, 5, 6;
+            3, 6, 9;
+        ];
+        assert!(a.is_symmetric());
+        assert!(!matrix![1, 2; 3, 4].is_symmetric());
+        assert!(Matrix::<3, 3, f64>::eye().is_symmetric());
+
+        let b = matrix![
+            1.0,   2.0 + 1e-9;
+            2.0,   4.0;
+        ];
+        assert!(!b.is_symmetric());
+        assert!(b.is_symmetric_tol(1e-8));
+        assert!(!b.is_symmetric_tol(1e-10));
+        assert!((b.transpose() * b).is_symmetric());
+    }
+
+    #[test]
+    fn is_diagonal() {
+        assert!(matrix![1, 0; 0, -3].is_diagonal());
+        assert!(!matrix![1, 0; 2, 1].is_diagonal());
+        assert!(Matrix::<3, 3, i32>::zeros().is_diagonal());
+
+        let d = matrix![
+            2.0,  1e-9, 0.0;
+            0.0,  3.0, -1e-9;
+            0.0,  0.0,  4.0;
+        ];
+        assert!(!d.is_diagonal());
+        assert!(d.is_diagonal_tol(1e-8));
+        assert!(!d.is_diagonal_tol(1e-10));
+    }
+
+    #[test]
+    fn is_orthogonal() {
+        let q = matrix![
+            0.0, -1.0, 0.0;
+            1.0,  0.0, 0.0;
+            0.0,  0.0, 1.0;
+        ];
+        assert!(q.is_orthogonal(0.0));
+        assert!(!(q * 2.0).is_orthogonal(1e-6));
+
+        let s = libm::sqrt(0.5);
+        let r = matrix![s, -s; s, s];
+        assert!(r.is_orthogonal(1e-12));
+        assert!(!matrix![1.0, 1.0; 0.0, 1.0].is_orthogonal(1e-6));
+    }
+
+    #[test]
+    fn dot() {
+        assert_eq!(vector![1.0; 2.0; 3.0].dot(&vector![4.0; 5.0; 6.0]), 32.0);
+        assert_eq!(vector![1.0, 2.0, 3.0].dot(&vector![4.0, 5.0, 6.0]), 32.0);
+        assert_eq!(matrix![1, 2; 3, 4].dot(&matrix![1, 0; 0, 1]), 5);
+    }
+
     #[test]
     fn cross() {
         let a = vector![3.0;-3.0; 1.0];
@@ -578,5 +2287,69 @@ mod tests {
             3.0, 2.0, 1.0;
         ];
         assert_eq!(m.trace(), 15.0);
+        assert_eq!(m.trace_by_ref(), 15.0);
+
+        // Only by-value addition is implemented here
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Wrapped(i32);
+        impl Zero for Wrapped {
+            fn zero() -> Self {
+                Wrapped(0)
+            }
+        }
+        impl Add for Wrapped {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Wrapped(self.0 + rhs.0)
+            }
+        }
+        let m =
+            Matrix::from_column_major_order([[Wrapped(1), Wrapped(2)], [Wrapped(3), Wrapped(4)]]);
+        assert_eq!(m.trace(), Wrapped(5));
+    }
+
+    #[test]
+    fn pow() {
+        let a = matrix![
+            1, 1;
+            1, 0;
+        ];
+        assert_eq!(a.pow(0), eye!(2, i32));
+        assert_eq!(a.pow(1), a);
+        assert_eq!(a.pow(3), a * a * a);
+        // Fibonacci numbers
+        assert_eq!(a.pow(10), matrix![89, 55; 55, 34]);
+    }
+
+    #[test]
+    fn symmetrize() {
+        let m = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 9.0;
+        ];
+        let sym = m.symmetrize();
+        let skew = m.antisymmetrize();
+        assert_eq!(sym, sym.T());
+        assert_eq!(skew, -skew.T());
+        assert_eq!(sym + skew, m);
+        assert_eq!(sym.trace(), m.trace());
+        assert_eq!(skew.trace(), 0.0);
+    }
+
+    #[test]
+    fn deviatoric() {
+        let m = matrix![
+            10.0, 2.0, 3.0;
+             2.0, 5.0, 1.0;
+             3.0, 1.0, 3.0;
+        ];
+        let dev = m.deviatoric();
+        let vol = m.volumetric();
+        assert_relative_eq!(dev.trace(), 0.0, epsilon = 1e-12);
+        assert_eq!(vol, eye!(3) * 6.0);
+        assert_relative_eq!(dev + vol, m, epsilon = 1e-12);
+        assert_eq!(dev[(0, 1)], 2.0);
+        assert_eq!(dev[(0, 0)], 4.0);
     }
 }
@@ -1,8 +1,10 @@
-use crate::num::{One, Zero};
-use crate::Matrix;
+use crate::num::{FromUsize, One, Zero};
+use crate::{Matrix, RowVector, Vector};
 
+use core::hint;
 use core::mem;
 use core::mem::MaybeUninit;
+use core::ops::{Add, Div, Mul, Neg};
 use core::ptr;
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -15,6 +17,58 @@ impl<const M: usize, const N: usize, T> Matrix<M, N, T> {
     pub const fn from_column_major_order(data: [[T; M]; N]) -> Self {
         Self { data }
     }
+
+    /// Create a new matrix from an array of rows.
+    ///
+    /// This is the layout of most textbook data and C arrays; each row is
+    /// written into its column-major slots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stack_algebra::*;
+    /// let m = Matrix::from_row_major([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(m, matrix![1, 2, 3; 4, 5, 6]);
+    /// ```
+    #[inline]
+    pub fn from_row_major(data: [[T; N]; M]) -> Self {
+        let mut m = Matrix::<M, N, MaybeUninit<T>>::uninit();
+        for (r, row) in data.into_iter().enumerate() {
+            for (c, v) in row.into_iter().enumerate() {
+                m[(r, c)] = MaybeUninit::new(v);
+            }
+        }
+        // SAFETY: every element was written in the loop above.
+        unsafe { m.assume_init() }
+    }
+
+    /// Create a new matrix from rows given as slices, checking their lengths.
+    ///
+    /// Returns `Err(i)` with the index of the first row whose length is not
+    /// `N`. This is what [`try_matrix!`] expands to.
+    pub fn try_from_rows(rows: &[&[T]; M]) -> Result<Self, usize>
+    where
+        T: Copy,
+    {
+        if let Some(i) = rows.iter().position(|row| row.len() != N) {
+            return Err(i);
+        }
+        Ok(Self::from_fn(|r, c| rows[r][c]))
+    }
+
+    /// Create a new matrix by calling `f(row, col)` for each element.
+    ///
+    /// Elements are produced in column-major order, matching
+    /// [`as_slice`][Matrix::as_slice].
+    #[inline]
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let iter = (0..N).flat_map(|c| (0..M).map(move |r| (r, c)));
+        // SAFETY: the iterator yields exactly `M * N` elements.
+        unsafe { collect_unchecked(iter.map(|(r, c)| f(r, c))) }
+    }
 }
 
 impl<const M: usize, const N: usize, T> Matrix<M, N, T>
@@ -57,6 +111,172 @@ where
     }
 }
 
+impl<const N: usize, T> Matrix<N, N, T>
+where
+    T: Copy,
+{
+    /// Create a symmetric matrix from its lower triangle in packed storage.
+    ///
+    /// The lower triangle is read column by column (the LAPACK packed layout),
+    /// e.g. `[a00, a10, a20, a11, a21, a22]` for a 3-by-3 matrix, and mirrored
+    /// into the upper triangle. The packed length `L` must equal
+    /// `N * (N + 1) / 2`, which is checked at compile time.
+    pub fn from_lower_packed<const L: usize>(data: &[T; L]) -> Self {
+        const {
+            assert!(
+                L == N * (N + 1) / 2,
+                "packed length must be N * (N + 1) / 2"
+            )
+        };
+        let mut m = Matrix::<N, N, MaybeUninit<T>>::uninit();
+        let mut k = 0;
+        for c in 0..N {
+            for r in c..N {
+                m[(r, c)] = MaybeUninit::new(data[k]);
+                m[(c, r)] = MaybeUninit::new(data[k]);
+                k += 1;
+            }
+        }
+        // SAFETY: every (r, c) with r >= c and its mirror (c, r) were written
+        // above, which covers all elements of the matrix.
+        unsafe { m.assume_init() }
+    }
+}
+
+impl<const N: usize, T> Matrix<N, N, T>
+where
+    T: Zero + One + Copy + Add<Output = T> + Mul<Output = T> + Neg<Output = T>,
+{
+    /// Create the 1D discrete Laplacian (second-difference operator).
+    ///
+    /// This is the tridiagonal matrix with `-2` on the diagonal and `1` on the
+    /// sub- and super-diagonals, i.e. the finite-difference stencil
+    /// `[1, -2, 1]` with zero (Dirichlet) boundary conditions.
+    pub fn laplacian_1d() -> Self {
+        let one = T::one();
+        let mut m = Self::zeros();
+        for i in 0..N {
+            m[(i, i)] = -(one + one);
+            if i + 1 < N {
+                m[(i + 1, i)] = one;
+                m[(i, i + 1)] = one;
+            }
+        }
+        m
+    }
+
+    /// Create the 2D discrete Laplacian on a `W`-by-`H` grid.
+    ///
+    /// The operator is the Kronecker sum `I_H ⊗ L_W + L_H ⊗ I_W` of the 1D
+    /// Laplacians, giving the 5-point stencil for grid values ordered with `x`
+    /// varying fastest. `N` must equal `W * H`, which is checked at compile
+    /// time.
+    pub fn laplacian_2d<const W: usize, const H: usize>() -> Self {
+        const { assert!(N == W * H, "grid size must be N = W * H") };
+        let lw = Matrix::<W, W, T>::laplacian_1d();
+        let lh = Matrix::<H, H, T>::laplacian_1d();
+        let a: Self = Matrix::<H, H, T>::eye().kron(&lw);
+        let b: Self = lh.kron(&Matrix::<W, W, T>::eye());
+        a + b
+    }
+}
+
+impl<const N: usize, T> Matrix<N, N, T>
+where
+    T: One + FromUsize + Div<Output = T>,
+{
+    /// Create the Hilbert matrix `H[(i, j)] = 1 / (i + j + 1)`.
+    ///
+    /// The Hilbert matrix is symmetric positive-definite but notoriously
+    /// ill-conditioned, which makes it a standard fixture for testing the
+    /// accuracy of solvers and inversions.
+    pub fn hilbert() -> Self {
+        Self::from_fn(|r, c| T::one() / T::from_usize(r + c + 1))
+    }
+}
+
+impl<const N: usize, T> Matrix<N, N, T>
+where
+    T: Copy,
+{
+    /// Create the circulant matrix whose first column is `first_column` and
+    /// whose `j`-th column is that column rolled down by `j`, i.e.
+    /// `C[(i, j)] = c[(i - j) mod N]`.
+    ///
+    /// Multiplying by a circulant matrix is a circular convolution with `c`,
+    /// and circulant matrices are diagonalized by the DFT.
+    pub fn circulant(first_column: &Vector<N, T>) -> Self {
+        Self::from_fn(|i, j| first_column[(i + N - j) % N])
+    }
+}
+
+impl<const M: usize, const N: usize, T> Matrix<M, N, T>
+where
+    T: Copy,
+{
+    /// Create the Toeplitz matrix with the given first column and first row,
+    /// i.e. `T[(i, j)] = c[i - j]` below the diagonal and `r[j - i]` above it.
+    ///
+    /// Every diagonal of a Toeplitz matrix is constant, which makes them
+    /// show up in convolutions and as autocorrelation matrices.
+    ///
+    /// The `(0, 0)` entries of `first_column` and `first_row` must agree: both
+    /// describe the main diagonal. If they differ, the column's value is used
+    /// and `first_row[0]` is ignored.
+    pub fn toeplitz(first_column: &Vector<M, T>, first_row: &RowVector<N, T>) -> Self {
+        Self::from_fn(|i, j| {
+            if i >= j {
+                first_column[i - j]
+            } else {
+                first_row[j - i]
+            }
+        })
+    }
+
+    /// Create the Hankel matrix with the given first column and last row, i.e.
+    /// `H[(i, j)] = h[i + j]` where `h` is `first_column` followed by
+    /// `last_row` without its first entry.
+    ///
+    /// Every anti-diagonal of a Hankel matrix is constant. They show up in
+    /// system identification and moment problems.
+    ///
+    /// The bottom-left entry is shared: `first_column[M - 1]` and
+    /// `last_row[0]` must agree. If they differ, the column's value is used.
+    pub fn hankel(first_column: &Vector<M, T>, last_row: &RowVector<N, T>) -> Self {
+        Self::from_fn(|i, j| {
+            let k = i + j;
+            if k < M {
+                first_column[k]
+            } else {
+                last_row[k + 1 - M]
+            }
+        })
+    }
+}
+
+impl<const M: usize, const N: usize, T> Matrix<M, N, T>
+where
+    T: One + Copy + Mul<Output = T>,
+{
+    /// Create the Vandermonde matrix of the sample points `x`, where column `j`
+    /// holds `x` raised elementwise to the power `j`.
+    ///
+    /// This is the design matrix for fitting a polynomial of degree `N - 1`
+    /// to samples taken at `x`.
+    pub fn vandermonde(x: &Vector<M, T>) -> Self {
+        let mut m = Matrix::<M, N, MaybeUninit<T>>::uninit();
+        for r in 0..M {
+            let mut p = T::one();
+            for c in 0..N {
+                m[(r, c)] = MaybeUninit::new(p);
+                p = p * x[r];
+            }
+        }
+        // SAFETY: every element was written in the loop above.
+        unsafe { m.assume_init() }
+    }
+}
+
 /// A macro for creating a matrix.
 #[macro_export]
 macro_rules! matrix {
@@ -65,6 +285,88 @@ macro_rules! matrix {
     };
 }
 
+/// A macro for creating a matrix from an array of rows.
+///
+/// Unlike [`matrix!`], each element may be an arbitrary expression.
+///
+/// ```
+/// # use stack_algebra::*;
+/// let x = 2.0;
+/// let m = matrix_row_major![[1.0, x], [x * x, 4.0]];
+/// assert_eq!(m, matrix![1.0, 2.0; 4.0, 4.0]);
+/// ```
+#[macro_export]
+macro_rules! matrix_row_major {
+    ($([$($elem:expr),* $(,)?]),* $(,)?) => {
+        $crate::Matrix::from_row_major([$([$($elem),*]),*])
+    };
+}
+
+/// A macro for creating a matrix that checks the row lengths at runtime.
+///
+/// Takes the same row syntax as [`matrix!`], but accepts arbitrary
+/// expressions and expands to a `Result`: a ragged input gives `Err(i)` with
+/// the index of the first row whose length differs from the first row,
+/// instead of failing to compile.
+///
+/// ```
+/// # use stack_algebra::*;
+/// let m = try_matrix![1, 2; 3, 4];
+/// assert_eq!(m, Ok(matrix![1, 2; 3, 4]));
+///
+/// let ragged = try_matrix![1, 2; 3, 4; 5];
+/// assert_eq!(ragged, Err(2));
+/// ```
+#[macro_export]
+macro_rules! try_matrix {
+    ($($first:expr),+ $(; $($elem:expr),+)* $(;)?) => {{
+        // Item names are not hygienic, so avoid clashing with the caller's names
+        const __TRY_MATRIX_COLS: usize = [$(stringify!($first)),+].len();
+        const __TRY_MATRIX_ROWS: usize =
+            [stringify!($($first),+) $(, stringify!($($elem),+))*].len();
+        $crate::Matrix::<__TRY_MATRIX_ROWS, __TRY_MATRIX_COLS, _>::try_from_rows(&[
+            &[$($first),+][..]
+            $(, &[$($elem),+][..])*
+        ])
+    }};
+}
+
+/// A macro for assembling a block matrix from two or four blocks.
+///
+/// `stack![a, b]` and `stack![a; b]` place two blocks side by side or on top
+/// of each other, as [`hstack`][crate::hstack] and [`vstack`][crate::vstack]
+/// do, and `stack![a, b; c, d]` assembles a 2-by-2 block matrix. The size of
+/// the result must be known from the context; the blocks are checked to be
+/// compatible with it at compile time.
+///
+/// ```
+/// # use stack_algebra::*;
+/// let a = matrix![1, 2; 3, 4];
+/// let i = eye!(2, i32);
+/// let m: Matrix<4, 4, _> = stack![a, i; i, a];
+/// assert_eq!(
+///     m,
+///     matrix![
+///         1, 2, 1, 0;
+///         3, 4, 0, 1;
+///         1, 0, 1, 2;
+///         0, 1, 3, 4;
+///     ]
+/// );
+/// ```
+#[macro_export]
+macro_rules! stack {
+    ($a:expr, $b:expr; $c:expr, $d:expr $(;)?) => {
+        $crate::vstack(&$crate::hstack(&$a, &$b), &$crate::hstack(&$c, &$d))
+    };
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::hstack(&$a, &$b)
+    };
+    ($a:expr; $b:expr $(;)?) => {
+        $crate::vstack(&$a, &$b)
+    };
+}
+
 /// A macro for composing vectors.
 #[macro_export]
 macro_rules! vector {
@@ -261,21 +563,21 @@ where
     Ok(unsafe { matrix.assume_init() })
 }
 
-// /// Like [`collect()`] except the caller must guarantee that the iterator will
-// /// yield enough elements to fill the matrix.
-// pub unsafe fn collect_unchecked<I, T, const M: usize, const N: usize>(iter: I) -> Matrix<M, N, T>
-// where
-//     I: IntoIterator<Item = T>,
-// {
-//     match collect(iter.into_iter()) {
-//         Ok(matrix) => matrix,
-//         Err(_) => {
-//             // SAFETY: the caller guarantees the iterator will yield enough
-//             // elements, so this error case can never be reached.
-//             unsafe { hint::unreachable_unchecked() }
-//         }
-//     }
-// }
+/// Like [`collect()`] except the caller must guarantee that the iterator will
+/// yield enough elements to fill the matrix.
+pub unsafe fn collect_unchecked<I, T, const M: usize, const N: usize>(iter: I) -> Matrix<M, N, T>
+where
+    I: IntoIterator<Item = T>,
+{
+    match collect(iter.into_iter()) {
+        Ok(matrix) => matrix,
+        Err(_) => {
+            // SAFETY: the caller guarantees the iterator will yield enough
+            // elements, so this error case can never be reached.
+            unsafe { hint::unreachable_unchecked() }
+        }
+    }
+}
 
 impl<T, const M: usize, const N: usize> FromIterator<T> for Matrix<M, N, T> {
     /// Create a new matrix from an iterator.
@@ -313,7 +615,128 @@ fn collect_panic<const M: usize, const N: usize>(len: usize) -> ! {
 
 #[cfg(test)]
 mod new_test {
+    use crate::Matrix;
     use approx::assert_relative_eq;
+
+    #[test]
+    fn try_matrix() {
+        const N: f64 = 2.0;
+        let x = N;
+        let m = try_matrix![1.0, N; x * x, 4.0;];
+        assert_eq!(m, Ok(matrix![1.0, 2.0; 4.0, 4.0]));
+        assert_eq!(try_matrix![1, 2, 3], Ok(matrix![1, 2, 3]));
+
+        assert_eq!(try_matrix![1, 2; 3], Err(1));
+        assert_eq!(try_matrix![1; 2, 3; 4], Err(1));
+        assert_eq!(try_matrix![1, 2; 3, 4; 5, 6, 7], Err(2));
+
+        let rows: [&[i32]; 2] = [&[1, 2], &[3, 4]];
+        assert_eq!(
+            Matrix::<2, 2, _>::try_from_rows(&rows),
+            Ok(matrix![1, 2; 3, 4])
+        );
+        assert_eq!(Matrix::<2, 3, _>::try_from_rows(&rows), Err(0));
+    }
+
+    #[test]
+    fn stack() {
+        let a = matrix![1, 2; 3, 4];
+        let b = matrix![5, 6; 7, 8];
+        let c = matrix![9, 10; 11, 12];
+        let d = matrix![13, 14; 15, 16];
+        let m: Matrix<4, 4, _> = stack![a, b; c, d];
+        let exp = matrix![
+             1,  2,  5,  6;
+             3,  4,  7,  8;
+             9, 10, 13, 14;
+            11, 12, 15, 16;
+        ];
+        assert_eq!(m, exp);
+
+        let m: Matrix<2, 4, _> = stack![a, b];
+        assert_eq!(m, matrix![1, 2, 5, 6; 3, 4, 7, 8]);
+        let m: Matrix<4, 2, _> = stack![a; c];
+        assert_eq!(m, matrix![1, 2; 3, 4; 9, 10; 11, 12]);
+
+        // Blocks of different shapes, e.g. an augmented system
+        let r = matrix![1.0, 0.0; 0.0, 1.0];
+        let t = vector![3.0; 4.0];
+        let m: Matrix<3, 3, _> = stack![r, t; matrix![0.0, 0.0], matrix![1.0]];
+        assert_eq!(m, matrix![1.0, 0.0, 3.0; 0.0, 1.0, 4.0; 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn circulant() {
+        let c = vector![1; 2; 3; 4];
+        let m = Matrix::circulant(&c);
+        let exp = matrix![
+            1, 4, 3, 2;
+            2, 1, 4, 3;
+            3, 2, 1, 4;
+            4, 3, 2, 1;
+        ];
+        assert_eq!(m, exp);
+        for j in 0..4 {
+            assert_eq!(m.col_vector(j), c.roll_rows(j as isize));
+        }
+    }
+
+    #[test]
+    fn toeplitz() {
+        let c = vector![1; 2; 3];
+        let r = vector![1, 5, 6, 7];
+        let exp = matrix![
+            1, 5, 6, 7;
+            2, 1, 5, 6;
+            3, 2, 1, 5;
+        ];
+        assert_eq!(Matrix::toeplitz(&c, &r), exp);
+
+        // The main diagonal comes from the first column
+        let r = vector![9, 5, 6, 7];
+        assert_eq!(Matrix::toeplitz(&c, &r), exp);
+
+        // A symmetric Toeplitz matrix is circulant when the column wraps around
+        let c = vector![1; 2; 3; 2];
+        assert_eq!(Matrix::toeplitz(&c, &c.transpose()), Matrix::circulant(&c));
+    }
+
+    #[test]
+    fn hankel() {
+        let c = vector![1; 2; 3];
+        let r = vector![3, 4, 5, 6];
+        let exp = matrix![
+            1, 2, 3, 4;
+            2, 3, 4, 5;
+            3, 4, 5, 6;
+        ];
+        assert_eq!(Matrix::hankel(&c, &r), exp);
+
+        // The shared corner comes from the first column
+        let r = vector![9, 4, 5, 6];
+        assert_eq!(Matrix::hankel(&c, &r), exp);
+
+        // Flipping the rows of a Hankel matrix gives a Toeplitz matrix
+        let t = Matrix::toeplitz(&vector![3; 2; 1], &vector![3, 4, 5, 6]);
+        assert_eq!(Matrix::from_fn(|i, j| exp[(2 - i, j)]), t);
+    }
+
+    #[test]
+    fn from_row_major() {
+        let exp = matrix![
+            1, 2, 3;
+            4, 5, 6;
+        ];
+        let m = Matrix::from_row_major([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m, exp);
+        assert_eq!(m.as_slice(), &[1, 4, 2, 5, 3, 6]);
+        assert_eq!(matrix_row_major![[1, 2, 3], [4, 5, 6],], exp);
+        assert_eq!(
+            matrix_row_major![[1], [2]],
+            Matrix::from_column_major_order([[1, 2]])
+        );
+    }
+
     #[test]
     fn diag() {
         let d = diag!(0.1, 0.2);
@@ -361,4 +784,91 @@ mod new_test {
         ];
         assert_relative_eq!(d, e, max_relative = 1e-6);
     }
+
+    #[test]
+    fn from_fn() {
+        let m = crate::Matrix::<3, 2, i32>::from_fn(|r, c| 10 * r as i32 + c as i32);
+        assert_eq!(m[(0, 0)], 0);
+        assert_eq!(m[(2, 0)], 20);
+        assert_eq!(m[(1, 1)], 11);
+        assert_eq!(m.as_slice(), &[0, 10, 20, 1, 11, 21]);
+
+        // Called in column-major order
+        let mut calls = 0;
+        let m = crate::Matrix::<3, 2, (usize, usize, usize)>::from_fn(|r, c| {
+            calls += 1;
+            (calls, r, c)
+        });
+        assert_eq!(m.as_slice()[3], (4, 0, 1));
+    }
+
+    #[test]
+    fn from_lower_packed() {
+        let m = crate::Matrix::<3, 3, f64>::from_lower_packed(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let e = matrix![
+            1.0, 2.0, 3.0;
+            2.0, 4.0, 5.0;
+            3.0, 5.0, 6.0;
+        ];
+        assert_eq!(m, e);
+    }
+
+    #[test]
+    fn laplacian_1d() {
+        let l = crate::Matrix::<4, 4, f64>::laplacian_1d();
+        let e = matrix![
+            -2.0,  1.0,  0.0,  0.0;
+             1.0, -2.0,  1.0,  0.0;
+             0.0,  1.0, -2.0,  1.0;
+             0.0,  0.0,  1.0, -2.0;
+        ];
+        assert_eq!(l, e);
+    }
+
+    #[test]
+    fn laplacian_2d() {
+        let l = crate::Matrix::<4, 4, f64>::laplacian_2d::<2, 2>();
+        let e = matrix![
+            -4.0,  1.0,  1.0,  0.0;
+             1.0, -4.0,  0.0,  1.0;
+             1.0,  0.0, -4.0,  1.0;
+             0.0,  1.0,  1.0, -4.0;
+        ];
+        assert_eq!(l, e);
+
+        // Interior point of a 3x3 grid sees all four neighbours
+        let l = crate::Matrix::<9, 9, f64>::laplacian_2d::<3, 3>();
+        assert_eq!(l[(4, 4)], -4.0);
+        assert_eq!((0..9).map(|j| l[(4, j)]).sum::<f64>(), 0.0);
+    }
+
+    #[test]
+    fn hilbert() {
+        let h = crate::Matrix::<5, 5, f64>::hilbert();
+        assert_eq!(h[(0, 0)], 1.0);
+        assert_eq!(h[(1, 2)], 0.25);
+        assert_eq!(h[(4, 4)], 1.0 / 9.0);
+        assert_eq!(h, h.T());
+
+        // Frobenius condition number ||H|| ||H^-1|| is in the hundreds of thousands
+        let cond = h.norm() * h.inv().unwrap().norm();
+        assert!(cond > 1e5);
+    }
+
+    #[test]
+    fn vandermonde() {
+        let x = vector![1.0; 2.0; -3.0; 0.5];
+        let v = crate::Matrix::<4, 3, f64>::vandermonde(&x);
+        let e = matrix![
+            1.0,  1.0,  1.0;
+            1.0,  2.0,  4.0;
+            1.0, -3.0,  9.0;
+            1.0,  0.5, 0.25;
+        ];
+        assert_eq!(v, e);
+        for i in 0..4 {
+            assert_eq!(v[(i, 0)], 1.0);
+            assert_eq!(v[(i, 1)], x[i]);
+        }
+    }
 }
@@ -12,6 +12,43 @@ pub trait Sqrt {
     fn sqrt(self) -> Self;
 }
 
+/// Defines the natural logarithm for a type.
+pub trait Ln {
+    /// Returns the natural logarithm of this type.
+    fn ln(self) -> Self;
+}
+
+/// Defines the sine function for a type.
+pub trait Sin {
+    /// Returns the sine of this type (in radians).
+    fn sin(self) -> Self;
+}
+
+/// Defines the cosine function for a type.
+pub trait Cos {
+    /// Returns the cosine of this type (in radians).
+    fn cos(self) -> Self;
+}
+
+/// Defines the inverse sine function for a type.
+pub trait Asin {
+    /// Returns the arcsine of this type (in radians, in `[-π/2, π/2]`).
+    fn asin(self) -> Self;
+}
+
+/// Defines the inverse cosine function for a type.
+pub trait Acos {
+    /// Returns the arccosine of this type (in radians, in `[0, π]`).
+    fn acos(self) -> Self;
+}
+
+/// Defines the four-quadrant inverse tangent for a type.
+pub trait Atan2 {
+    /// Returns the angle of the point `(x, y)` where `self` is `y` (in
+    /// radians, in `[-π, π]`).
+    fn atan2(self, x: Self) -> Self;
+}
+
 /// Defines a multiplicative identity element for a type.
 pub trait One {
     /// Returns the multiplicative identity element of this type.
@@ -24,6 +61,44 @@ pub trait Zero {
     fn zero() -> Self;
 }
 
+/// Defines the machine epsilon for a type.
+pub trait Epsilon {
+    /// Returns the difference between `1.0` and the next representable value.
+    fn epsilon() -> Self;
+}
+
+/// Defines Archimedes' constant π for a type.
+pub trait Pi {
+    /// Returns the value of π in this type.
+    fn pi() -> Self;
+}
+
+/// Defines a lossy conversion from `usize` for a type.
+pub trait FromUsize {
+    /// Returns the value of `n` in this type.
+    fn from_usize(n: usize) -> Self;
+}
+
+/// Marker for "plain old data" types that can be safely viewed as bytes.
+///
+/// # Safety
+///
+/// Implementors must be `Copy`, contain no padding and no pointers, and every
+/// bit pattern of the right size must be a valid value of the type.
+pub unsafe trait Pod: Copy + 'static {}
+
+/// Defines a fixed-size little-endian byte encoding for a type.
+pub trait LeBytes: Sized {
+    /// The number of bytes in the encoding.
+    const SIZE: usize;
+
+    /// Writes the little-endian encoding of this value into `out[..Self::SIZE]`.
+    fn write_le_bytes(self, out: &mut [u8]);
+
+    /// Reads a value from its little-endian encoding in `bytes[..Self::SIZE]`.
+    fn read_le_bytes(bytes: &[u8]) -> Self;
+}
+
 macro_rules! impl_one {
     ($one:literal $($ty:ty)+) => ($(
         impl One for $ty {
@@ -46,6 +121,39 @@ macro_rules! impl_zero {
     )+)
 }
 
+macro_rules! impl_epsilon {
+    ($($ty:ident)+) => ($(
+        impl Epsilon for $ty {
+            #[inline]
+            fn epsilon() -> $ty {
+                $ty::EPSILON
+            }
+        }
+    )+)
+}
+
+macro_rules! impl_pi {
+    ($($ty:ident)+) => ($(
+        impl Pi for $ty {
+            #[inline]
+            fn pi() -> $ty {
+                core::$ty::consts::PI
+            }
+        }
+    )+)
+}
+
+macro_rules! impl_from_usize {
+    ($($ty:ty)+) => ($(
+        impl FromUsize for $ty {
+            #[inline]
+            fn from_usize(n: usize) -> $ty {
+                n as $ty
+            }
+        }
+    )+)
+}
+
 macro_rules! impl_abs {
     ($($ty:ident)+) => ($(
         impl Abs for $ty {
@@ -81,6 +189,78 @@ impl Sqrt for f64 {
     }
 }
 
+impl Ln for f32 {
+    fn ln(self) -> Self {
+        libm::logf(self)
+    }
+}
+
+impl Ln for f64 {
+    fn ln(self) -> Self {
+        libm::log(self)
+    }
+}
+
+impl Sin for f32 {
+    fn sin(self) -> Self {
+        libm::sinf(self)
+    }
+}
+
+impl Sin for f64 {
+    fn sin(self) -> Self {
+        libm::sin(self)
+    }
+}
+
+impl Cos for f32 {
+    fn cos(self) -> Self {
+        libm::cosf(self)
+    }
+}
+
+impl Cos for f64 {
+    fn cos(self) -> Self {
+        libm::cos(self)
+    }
+}
+
+impl Asin for f32 {
+    fn asin(self) -> Self {
+        libm::asinf(self)
+    }
+}
+
+impl Asin for f64 {
+    fn asin(self) -> Self {
+        libm::asin(self)
+    }
+}
+
+impl Acos for f32 {
+    fn acos(self) -> Self {
+        libm::acosf(self)
+    }
+}
+
+impl Acos for f64 {
+    fn acos(self) -> Self {
+        libm::acos(self)
+    }
+}
+
+impl Atan2 for f32 {
+    fn atan2(self, x: Self) -> Self {
+        libm::atan2f(self, x)
+    }
+}
+
+impl Atan2 for f64 {
+    fn atan2(self, x: Self) -> Self {
+        libm::atan2(self, x)
+    }
+}
+
 macro_rules! impl_abs_self {
     ($($ty:ident)+) => ($(
         impl Abs for $ty {
@@ -92,6 +272,34 @@ macro_rules! impl_abs_self {
     )+)
 }
 
+macro_rules! impl_pod {
+    ($($ty:ty)+) => ($(
+        // SAFETY: primitive integers and floats have no padding and every bit
+        // pattern is a valid value.
+        unsafe impl Pod for $ty {}
+    )+)
+}
+
+macro_rules! impl_le_bytes {
+    ($($ty:ty)+) => ($(
+        impl LeBytes for $ty {
+            const SIZE: usize = core::mem::size_of::<$ty>();
+
+            #[inline]
+            fn write_le_bytes(self, out: &mut [u8]) {
+                out[..Self::SIZE].copy_from_slice(&self.to_le_bytes());
+            }
+
+            #[inline]
+            fn read_le_bytes(bytes: &[u8]) -> Self {
+                let mut buf = [0; core::mem::size_of::<$ty>()];
+                buf.copy_from_slice(&bytes[..Self::SIZE]);
+                <$ty>::from_le_bytes(buf)
+            }
+        }
+    )+)
+}
+
 impl_one! { true bool }
 impl_one! { 1 usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
 impl_one! { 1.0 f32 f64 }
@@ -102,3 +310,13 @@ impl_zero! { 0.0 f32 f64 }
 
 impl_abs_self! { usize u8 u16 u32 u64 u128 }
 impl_abs! { isize i8 i16 i32 i64 i128 }
+
+impl_epsilon! { f32 f64 }
+
+impl_pi! { f32 f64 }
+
+impl_from_usize! { f32 f64 }
+
+impl_pod! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+
+impl_le_bytes! { u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 f32 f64 }
@@ -5,7 +5,7 @@ use core::ops::{
 };
 
 use crate::index::MatrixIndex;
-use crate::num::Zero;
+use crate::num::{Abs, One, Zero};
 use crate::Matrix;
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -121,7 +121,7 @@ impl_op_scalar! { Rem, rem }
 macro_rules! impl_op_assign_scalar {
     ($trt:ident, $meth:ident) => {
         // Matrix += T
-        impl<'a, T, const M: usize, const N: usize> $trt<T> for Matrix<M, N, T>
+        impl<T, const M: usize, const N: usize> $trt<T> for Matrix<M, N, T>
         where
             T: Copy + $trt<T>,
         {
@@ -261,6 +261,49 @@ impl_op_mul! {  Matrix<M,N,T>, &Matrix<N,P,T> }
 impl_op_mul! { &Matrix<M,N,T>,  Matrix<N,P,T> }
 impl_op_mul! { &Matrix<M,N,T>, &Matrix<N,P,T> }
 
+////////////////////////////////////////////////////////////////////////////////
+// Matrix / Matrix
+////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_op_div {
+    ($lhs:ty, $rhs:ty) => {
+        impl<T, const N: usize> Div<$rhs> for $lhs
+        where
+            T: Abs
+                + PartialOrd
+                + Copy
+                + Zero
+                + One
+                + Sum
+                + Add<Output = T>
+                + Neg<Output = T>
+                + Mul<Output = T>
+                + Sub<Output = T>
+                + Div<Output = T>,
+        {
+            type Output = Matrix<N, N, T>;
+
+            /// Right division `self * rhs⁻¹`.
+            ///
+            /// # Panics
+            ///
+            /// If `rhs` is singular. Use [`Matrix::try_div`] to handle this case.
+            #[track_caller]
+            fn div(self, rhs: $rhs) -> Self::Output {
+                match self.try_div(&rhs) {
+                    Some(m) => m,
+                    None => panic!("attempt to divide by a singular matrix"),
+                }
+            }
+        }
+    };
+}
+
+impl_op_div! {  Matrix<N,N,T>,  Matrix<N,N,T> }
+impl_op_div! {  Matrix<N,N,T>, &Matrix<N,N,T> }
+impl_op_div! { &Matrix<N,N,T>,  Matrix<N,N,T> }
+impl_op_div! { &Matrix<N,N,T>, &Matrix<N,N,T> }
+
 ////////////////////////////////////////////////////////////////////////////////
 // Matrix += Matrix
 ////////////////////////////////////////////////////////////////////////////////
@@ -286,6 +329,26 @@ impl_op_assign! { impl AddAssign<&Matrix<M,N,T>>, add_assign }
 impl_op_assign! { impl SubAssign< Matrix<M,N,T>>, sub_assign }
 impl_op_assign! { impl SubAssign<&Matrix<M,N,T>>, sub_assign }
 
+////////////////////////////////////////////////////////////////////////////////
+// Matrix *= Matrix
+////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_op_mul_assign {
+    ($rhs:ty) => {
+        impl<T, const N: usize> MulAssign<$rhs> for Matrix<N, N, T>
+        where
+            T: Copy + Zero + Mul<Output = T> + Sum,
+        {
+            fn mul_assign(&mut self, other: $rhs) {
+                *self = *self * other;
+            }
+        }
+    };
+}
+
+impl_op_mul_assign! {  Matrix<N,N,T> }
+impl_op_mul_assign! { &Matrix<N,N,T> }
+
 ////////////////////////////////////////////////////////////////////////////////
 // -Matrix
 ////////////////////////////////////////////////////////////////////////////////
@@ -390,6 +453,75 @@ mod tests {
         assert_eq!(res, m);
     }
     #[test]
+    fn scalar_assign() {
+        fn apply<X: core::ops::AddAssign<f64> + for<'a> core::ops::MulAssign<&'a f64>>(x: &mut X) {
+            *x += 1.0;
+            *x *= &2.0;
+        }
+
+        let m = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+        ];
+
+        // By value
+        let mut r = m;
+        r += 2.0;
+        assert_eq!(r, m + 2.0);
+        let mut r = m;
+        r -= 2.0;
+        assert_eq!(r, m - 2.0);
+        let mut r = m;
+        r *= 2.0;
+        assert_eq!(r, m * 2.0);
+        let mut r = m;
+        r /= 2.0;
+        assert_eq!(r, m / 2.0);
+        let mut r = m;
+        r %= 2.0;
+        assert_eq!(r, m % 2.0);
+
+        // By reference
+        let s = 3.0;
+        let mut r = m;
+        r += &s;
+        assert_eq!(r, m + s);
+        let mut r = m;
+        r -= &s;
+        assert_eq!(r, m - s);
+        let mut r = m;
+        r *= &s;
+        assert_eq!(r, m * s);
+        let mut r = m;
+        r /= &s;
+        assert_eq!(r, m / s);
+        let mut r = m;
+        r %= &s;
+        assert_eq!(r, m % s);
+
+        // Through `&mut Matrix` in a generic function
+        let mut r = m;
+        apply(&mut r);
+        assert_eq!(r, (m + 1.0) * 2.0);
+    }
+    #[test]
+    fn mat_mul_assign() {
+        let a = matrix![
+            1.0, 2.0;
+            3.0, 4.0;
+        ];
+        let b = matrix![
+            0.0, 1.0;
+            -1.0, 2.0;
+        ];
+        let mut m = a;
+        m *= b;
+        assert_eq!(m, a * b);
+        assert_eq!(m, matrix![-2.0, 5.0; -4.0, 11.0]);
+        m *= &b;
+        assert_eq!(m, a * b * b);
+    }
+    #[test]
     fn mat_add() {
         let m = matrix![
             1.0, 2.0, 3.0;
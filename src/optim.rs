@@ -0,0 +1,254 @@
+//! Numerical differentiation and nonlinear solvers.
+
+use core::{
+    iter::Sum,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::{
+    num::{Abs, Epsilon, FromUsize, One, Sqrt, Zero},
+    Matrix, Vector,
+};
+
+/// Compute the gradient of `f` at `x` using central differences with step `h`.
+///
+/// Each component is approximated as `(f(x + h eᵢ) - f(x - h eᵢ)) / 2h`, which
+/// has an error of order `h²`. This is useful for checking analytic gradients
+/// or for gradient descent on small problems.
+pub fn numerical_gradient<F, const N: usize, T>(f: F, x: &Vector<N, T>, h: T) -> Vector<N, T>
+where
+    F: Fn(&Vector<N, T>) -> T,
+    T: Copy + Add<Output = T> + Sub<Output = T> + Div<Output = T>,
+{
+    let two_h = h + h;
+    let mut x = *x;
+    let mut grad = x;
+    for i in 0..N {
+        let xi = x[i];
+        x[i] = xi + h;
+        let fp = f(&x);
+        x[i] = xi - h;
+        let fm = f(&x);
+        x[i] = xi;
+        grad[i] = (fp - fm) / two_h;
+    }
+    grad
+}
+
+/// Compute the Jacobian of `f` at `x` using central differences with step `h`.
+///
+/// Column `j` of the result approximates `∂f/∂xⱼ` as
+/// `(f(x + h eⱼ) - f(x - h eⱼ)) / 2h`.
+pub fn numerical_jacobian<F, const M: usize, const N: usize, T>(
+    f: F,
+    x: &Vector<N, T>,
+    h: T,
+) -> Matrix<M, N, T>
+where
+    F: Fn(&Vector<N, T>) -> Vector<M, T>,
+    T: Copy + Zero + Add<Output = T> + Sub<Output = T> + Div<Output = T>,
+{
+    let two_h = h + h;
+    let mut x = *x;
+    let mut jac = Matrix::zeros();
+    for j in 0..N {
+        let xj = x[j];
+        x[j] = xj + h;
+        let fp = f(&x);
+        x[j] = xj - h;
+        let fm = f(&x);
+        x[j] = xj;
+        for i in 0..M {
+            jac[(i, j)] = (fp[i] - fm[i]) / two_h;
+        }
+    }
+    jac
+}
+
+/// Solve the nonlinear system `f(x) = 0` with the Newton-Raphson method,
+/// starting from `x0`.
+///
+/// Each iteration solves `J Δx = f(x)` with the [numerical
+/// Jacobian](numerical_jacobian) `J` and updates `x -= Δx`, until the residual
+/// norm `||f(x)||` is at most `tol`. Returns `None` if this does not happen
+/// within `max_iters` iterations or if the Jacobian becomes singular.
+pub fn newton_solve<F, const N: usize, T>(
+    f: F,
+    x0: &Vector<N, T>,
+    tol: T,
+    max_iters: usize,
+) -> Option<Vector<N, T>>
+where
+    F: Fn(&Vector<N, T>) -> Vector<N, T>,
+    T: Abs
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Epsilon
+        + Sqrt
+        + Sum
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    let h = T::epsilon().sqrt();
+    let mut x = *x0;
+    for _ in 0..max_iters {
+        let fx = f(&x);
+        if fx.norm() <= tol {
+            return Some(x);
+        }
+        let dx = numerical_jacobian(&f, &x, h).solve(&fx)?;
+        x = x - dx;
+    }
+    (f(&x).norm() <= tol).then_some(x)
+}
+
+/// Minimize `||r(x)||²` for the residual function `r` with the
+/// Levenberg-Marquardt method, starting from `x0`.
+///
+/// Each iteration solves the damped normal equations `(JᵀJ + λI) Δx = Jᵀr`
+/// with the [numerical Jacobian](numerical_jacobian) `J` and tries
+/// `x -= Δx`. A step that lowers the cost is accepted and the damping `λ`
+/// is decreased, moving towards Gauss-Newton. Otherwise the step is rejected
+/// and `λ` is increased, moving towards gradient descent.
+///
+/// Converges once the gradient norm `||Jᵀr||` or the norm of an accepted step
+/// is at most `tol`. Returns `None` if this does not happen within `max_iters`
+/// iterations or if the damped system becomes singular.
+pub fn levenberg_marquardt<F, const M: usize, const N: usize, T>(
+    residual: F,
+    x0: &Vector<N, T>,
+    tol: T,
+    max_iters: usize,
+) -> Option<Vector<N, T>>
+where
+    F: Fn(&Vector<N, T>) -> Vector<M, T>,
+    T: Abs
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Epsilon
+        + FromUsize
+        + Sqrt
+        + Sum
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    let h = T::epsilon().sqrt();
+    let ten = T::from_usize(10);
+    let mut lambda = T::one() / T::from_usize(1000);
+
+    let mut x = *x0;
+    let mut r = residual(&x);
+    let mut cost = r.dot(&r);
+    let mut jac = numerical_jacobian(&residual, &x, h);
+    for _ in 0..max_iters {
+        let grad = jac.transposed() * r;
+        if grad.norm() <= tol {
+            return Some(x);
+        }
+        let mut jtj = jac.transposed() * jac;
+        for i in 0..N {
+            jtj[(i, i)] = jtj[(i, i)] + lambda;
+        }
+        let dx = jtj.solve(&grad)?;
+
+        let x_new = x - dx;
+        let r_new = residual(&x_new);
+        let cost_new = r_new.dot(&r_new);
+        if cost_new < cost {
+            x = x_new;
+            r = r_new;
+            cost = cost_new;
+            if dx.norm() <= tol {
+                return Some(x);
+            }
+            jac = numerical_jacobian(&residual, &x, h);
+            lambda = lambda / ten;
+        } else {
+            lambda = lambda * ten;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn numerical_gradient() {
+        // f(x) = ½ xᵀ A x + bᵀ x has gradient A x + b for symmetric A
+        let a = matrix![
+            4.0, 1.0;
+            1.0, 3.0;
+        ];
+        let b = vector![-1.0; 2.0];
+        let f = |x: &Vector<2, f64>| 0.5 * (x.transpose() * a * x)[0] + b.dot(x);
+
+        let x = vector![0.5; -2.0];
+        let g = super::numerical_gradient(f, &x, 1e-5);
+        let exp = a * x + b;
+        assert_relative_eq!(g, exp, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn numerical_jacobian() {
+        // Polar to Cartesian coordinates, plus the squared radius
+        let f = |x: &Vector<2, f64>| vector![x[0] * libm::cos(x[1]); x[0] * libm::sin(x[1]); x[0] * x[0]];
+
+        let (r, t) = (2.0, 0.7);
+        let jac = super::numerical_jacobian(f, &vector![r; t], 1e-5);
+        let exp = matrix![
+            libm::cos(t), -r * libm::sin(t);
+            libm::sin(t),  r * libm::cos(t);
+            2.0 * r,       0.0;
+        ];
+        assert_relative_eq!(jac, exp, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn newton_solve() {
+        // Intersection of the circle x² + y² = 4 with the parabola y = x²
+        let f = |v: &Vector<2, f64>| vector![v[0] * v[0] + v[1] * v[1] - 4.0; v[1] - v[0] * v[0]];
+        let x = super::newton_solve(f, &vector![1.0; 1.0], 1e-12, 20).unwrap();
+        assert_relative_eq!(f(&x), vector![0.0; 0.0], epsilon = 1e-12);
+        let y = (libm::sqrt(17.0) - 1.0) / 2.0;
+        assert_relative_eq!(x, vector![libm::sqrt(y); y], epsilon = 1e-9);
+
+        // x² + 1 = 0 has no real root
+        let g = |v: &Vector<2, f64>| vector![v[0] * v[0] + 1.0; v[1]];
+        assert_eq!(super::newton_solve(g, &vector![1.0; 0.0], 1e-12, 50), None);
+        // The Jacobian is singular at the start
+        assert_eq!(super::newton_solve(g, &vector![0.0; 1.0], 1e-12, 50), None);
+    }
+
+    #[test]
+    fn levenberg_marquardt() {
+        // Fit y = a exp(b t) to clean samples of a = 2, b = -0.5
+        let t = vector![0.0; 0.5; 1.0; 1.5; 2.0; 3.0; 4.0; 5.0];
+        let y = t.map(|t| 2.0 * libm::exp(-0.5 * t));
+        let residual = |p: &Vector<2, f64>| t.map(|t| p[0] * libm::exp(p[1] * t)) - y;
+
+        let p = super::levenberg_marquardt(residual, &vector![1.0; 0.0], 1e-10, 100).unwrap();
+        assert_relative_eq!(p, vector![2.0; -0.5], epsilon = 1e-6);
+        assert_relative_eq!(residual(&p), Matrix::zeros(), epsilon = 1e-6);
+
+        // Too few iterations to get there
+        assert_eq!(
+            super::levenberg_marquardt(residual, &vector![1.0; 0.0], 1e-10, 1),
+            None
+        );
+    }
+}
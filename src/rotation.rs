@@ -0,0 +1,611 @@
+#![allow(non_snake_case)]
+
+//! Rotation matrices, conversions to and from angles, and homogeneous
+//! transforms.
+
+use core::{
+    iter::Sum,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::{
+    num::{Abs, Acos, Atan2, Cos, Epsilon, FromUsize, One, Sin, Sqrt, Zero},
+    Matrix, Vector,
+};
+
+impl<T> Matrix<2, 2, T>
+where
+    T: Copy + Sin + Cos + Neg<Output = T>,
+{
+    /// Create the 2D rotation matrix for a counter-clockwise rotation by
+    /// `theta` radians.
+    pub fn rotation(theta: T) -> Self {
+        let (s, c) = (theta.sin(), theta.cos());
+        Self::from_column_major_order([[c, s], [-s, c]])
+    }
+}
+
+impl<T> Matrix<2, 2, T>
+where
+    T: Copy + Atan2,
+{
+    /// Recover the angle of a 2D rotation matrix, in `[-π, π]`.
+    pub fn rotation_angle(&self) -> T {
+        self[(1, 0)].atan2(self[(0, 0)])
+    }
+}
+
+impl<T> Matrix<3, 3, T>
+where
+    T: Copy + Sin + Cos + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Neg<Output = T>,
+{
+    /// Create a 3D rotation matrix from roll, pitch and yaw angles (in
+    /// radians).
+    ///
+    /// The result is `R = Rz(yaw) Ry(pitch) Rx(roll)`: a rotation by `roll`
+    /// about the fixed x-axis, followed by `pitch` about the fixed y-axis and
+    /// `yaw` about the fixed z-axis. Rotations are right-handed, i.e.
+    /// counter-clockwise when looking down the axis towards the origin.
+    ///
+    /// This is the inverse of [`to_euler_xyz`][Self::to_euler_xyz] away from
+    /// gimbal lock.
+    pub fn from_euler_xyz(roll: T, pitch: T, yaw: T) -> Self {
+        let (sr, cr) = (roll.sin(), roll.cos());
+        let (sp, cp) = (pitch.sin(), pitch.cos());
+        let (sy, cy) = (yaw.sin(), yaw.cos());
+        Self::from_column_major_order([
+            [cy * cp, sy * cp, -sp],
+            [cy * sp * sr - sy * cr, sy * sp * sr + cy * cr, cp * sr],
+            [cy * sp * cr + sy * sr, sy * sp * cr - cy * sr, cp * cr],
+        ])
+    }
+}
+
+impl<T> Matrix<3, 3, T>
+where
+    T: Copy + One + Sin + Cos + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Create the rotation by `angle` radians about the unit vector `axis`
+    /// with Rodrigues' formula `R = cos θ I + sin θ [k]ₓ + (1 - cos θ) k kᵀ`.
+    ///
+    /// The rotation is right-handed. `axis` is assumed to have unit length.
+    pub fn from_axis_angle(axis: &Vector<3, T>, angle: T) -> Self {
+        let (s, c) = (angle.sin(), angle.cos());
+        let t = T::one() - c;
+        let (x, y, z) = (axis[0], axis[1], axis[2]);
+        Self::from_column_major_order([
+            [c + t * x * x, t * x * y + s * z, t * x * z - s * y],
+            [t * x * y - s * z, c + t * y * y, t * y * z + s * x],
+            [t * x * z + s * y, t * y * z - s * x, c + t * z * z],
+        ])
+    }
+}
+
+impl<T> Matrix<3, 3, T>
+where
+    T: Copy
+        + Zero
+        + One
+        + PartialOrd
+        + Sqrt
+        + Atan2
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>,
+{
+    /// Recover the unit rotation axis and the angle in `[0, π]` of a rotation
+    /// matrix, the inverse of [`from_axis_angle`][Self::from_axis_angle].
+    ///
+    /// For angles up to `π/2` the axis comes from the skew-symmetric part
+    /// `R - Rᵀ = 2 sin θ [k]ₓ`. Beyond that, where `sin θ` loses precision
+    /// towards `θ = π`, it is taken from the symmetric part
+    /// `k kᵀ = (R - cos θ I) / (1 - cos θ)`. At `θ = π` both `k` and `-k`
+    /// describe the same rotation and either may be returned. For the
+    /// identity the axis is arbitrary and `x` is returned.
+    pub fn to_axis_angle(&self) -> (Vector<3, T>, T) {
+        let r = self;
+        let one = T::one();
+        let two = one + one;
+        let v = Vector::from_column_major_order([[
+            r[(2, 1)] - r[(1, 2)],
+            r[(0, 2)] - r[(2, 0)],
+            r[(1, 0)] - r[(0, 1)],
+        ]]);
+        let v_norm = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+        let cos = (r[(0, 0)] + r[(1, 1)] + r[(2, 2)] - one) / two;
+        let angle = (v_norm / two).atan2(cos);
+
+        if cos > T::zero() {
+            if v_norm == T::zero() {
+                let x = Vector::from_column_major_order([[one, T::zero(), T::zero()]]);
+                return (x, angle);
+            }
+            return (v / v_norm, angle);
+        }
+
+        // Largest diagonal element of (R + Rᵀ) / 2 - cos θ I gives the best
+        // conditioned column of (1 - cos θ) k kᵀ
+        let mut i = 0;
+        for j in 1..3 {
+            if r[(j, j)] > r[(i, i)] {
+                i = j;
+            }
+        }
+        let mut k = Vector::<3, T>::zeros();
+        for j in 0..3 {
+            k[j] = if j == i {
+                r[(j, j)] - cos
+            } else {
+                (r[(j, i)] + r[(i, j)]) / two
+            };
+        }
+        let k_norm = (k[0] * k[0] + k[1] * k[1] + k[2] * k[2]).sqrt();
+        let k = k / k_norm;
+        // The skew-symmetric part points along +k for sin θ > 0
+        if k[0] * v[0] + k[1] * v[1] + k[2] * v[2] < T::zero() {
+            (-k, angle)
+        } else {
+            (k, angle)
+        }
+    }
+}
+
+impl<T> Matrix<3, 3, T>
+where
+    T: Copy + Atan2 + Sqrt + Add<Output = T> + Mul<Output = T> + Neg<Output = T>,
+{
+    /// Recover the roll, pitch and yaw angles `(x, y, z)` of a 3D rotation
+    /// matrix `R = Rz(yaw) Ry(pitch) Rx(roll)`.
+    ///
+    /// Pitch is in `[-π/2, π/2]`, roll and yaw are in `[-π, π]`. Roll and yaw
+    /// are not meaningful at gimbal lock, see
+    /// [`to_euler_xyz`][Self::to_euler_xyz] for a version that handles it.
+    pub fn euler_angles(&self) -> (T, T, T) {
+        let r = self;
+        let roll = r[(2, 1)].atan2(r[(2, 2)]);
+        let cos_pitch = (r[(2, 1)] * r[(2, 1)] + r[(2, 2)] * r[(2, 2)]).sqrt();
+        let pitch = (-r[(2, 0)]).atan2(cos_pitch);
+        let yaw = r[(1, 0)].atan2(r[(0, 0)]);
+        (roll, pitch, yaw)
+    }
+}
+
+impl<T> Matrix<3, 3, T>
+where
+    T: Copy
+        + Zero
+        + Epsilon
+        + PartialOrd
+        + Atan2
+        + Sqrt
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Neg<Output = T>,
+{
+    /// Decompose a 3D rotation matrix into roll, pitch and yaw angles
+    /// `(x, y, z)` such that `R = Rz(yaw) Ry(pitch) Rx(roll)`.
+    ///
+    /// Pitch is in `[-π/2, π/2]`, roll and yaw are in `[-π, π]`.
+    ///
+    /// At gimbal lock (pitch = ±90°) roll and yaw rotate about the same axis
+    /// and only their difference (or sum) is determined. In that case yaw is
+    /// set to zero and the whole rotation about that axis is returned as roll.
+    pub fn to_euler_xyz(&self) -> (T, T, T) {
+        let r = self;
+        let cos_pitch = (r[(2, 1)] * r[(2, 1)] + r[(2, 2)] * r[(2, 2)]).sqrt();
+        let pitch = (-r[(2, 0)]).atan2(cos_pitch);
+        if cos_pitch <= T::epsilon() {
+            let roll = (-r[(1, 2)]).atan2(r[(1, 1)]);
+            (roll, pitch, T::zero())
+        } else {
+            let roll = r[(2, 1)].atan2(r[(2, 2)]);
+            let yaw = r[(1, 0)].atan2(r[(0, 0)]);
+            (roll, pitch, yaw)
+        }
+    }
+}
+
+impl<T> Matrix<3, 3, T>
+where
+    T: Copy
+        + Zero
+        + One
+        + PartialOrd
+        + Acos
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>,
+{
+    /// Compute the geodesic angle in `[0, π]` between two rotations,
+    /// `acos((tr(RᵀR') - 1) / 2)`: the angle of the rotation taking `self`
+    /// onto `other`.
+    pub fn angular_distance(&self, other: &Matrix<3, 3, T>) -> T {
+        let one = T::one();
+        // tr(RᵀR') is the sum of the element-wise products
+        let mut tr = T::zero();
+        for (&a, &b) in self.as_slice().iter().zip(other.as_slice()) {
+            tr = tr + a * b;
+        }
+        // Clamp against rounding slightly outside of [-1, 1]
+        let cos = (tr - one) / (one + one);
+        let cos = if cos > one {
+            one
+        } else if cos < -one {
+            -one
+        } else {
+            cos
+        };
+        cos.acos()
+    }
+}
+
+impl<T> Matrix<3, 3, T>
+where
+    T: Abs
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Sum
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Check whether the matrix is a proper rotation: orthogonal within `eps`
+    /// (see [`is_orthogonal`][Self::is_orthogonal]) and with determinant
+    /// within `eps` of `+1`, which rules out reflections.
+    pub fn is_rotation(&self, eps: T) -> bool {
+        self.is_orthogonal(eps) && (self.det() - T::one()).abs() <= eps
+    }
+}
+
+impl<T> Matrix<3, 3, T>
+where
+    T: Abs
+        + Sqrt
+        + Epsilon
+        + FromUsize
+        + PartialOrd
+        + Copy
+        + Zero
+        + One
+        + Sum
+        + Add<Output = T>
+        + Neg<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>,
+{
+    /// Compute the chordal L2 mean of `K` rotations.
+    ///
+    /// The element-wise mean of the matrices is projected back onto the
+    /// rotations with its polar decomposition, giving the rotation closest to
+    /// the mean in the Frobenius norm.
+    ///
+    /// Returns `None` if the mean is numerically singular, in which case the
+    /// closest rotation is not unique. This happens when the rotations cancel
+    /// out, e.g. the identity and a half turn, but not when they are
+    /// reasonably close to each other.
+    pub fn average_rotations<const K: usize>(
+        rots: &[Matrix<3, 3, T>; K],
+    ) -> Option<Matrix<3, 3, T>> {
+        const { assert!(K > 0, "cannot average zero rotations") };
+        let mut mean = Matrix::<3, 3, T>::zeros();
+        for R in rots {
+            mean = mean + R;
+        }
+        let mean = mean / T::from_usize(K);
+
+        // With `mean * V = U * diag(σ)` the orthogonal polar factor is `U Vᵀ`
+        let (W, sigma, V) = mean.jacobi_svd();
+        let mut sigma_min = sigma[0];
+        let mut sigma_max = sigma[0];
+        for &s in &sigma[1..] {
+            if s < sigma_min {
+                sigma_min = s;
+            }
+            if s > sigma_max {
+                sigma_max = s;
+            }
+        }
+        if sigma_min <= sigma_max * T::epsilon() * T::from_usize(3) {
+            return None;
+        }
+        let mut U = W;
+        for (j, &s) in sigma.iter().enumerate() {
+            for i in 0..3 {
+                U[(i, j)] = U[(i, j)] / s;
+            }
+        }
+        // Flip the weakest direction if the polar factor is a reflection
+        if U.det() * V.det() < T::zero() {
+            let mut k = 0;
+            for j in 1..3 {
+                if sigma[j] < sigma[k] {
+                    k = j;
+                }
+            }
+            for i in 0..3 {
+                U[(i, k)] = -U[(i, k)];
+            }
+        }
+        Some(U * V.T())
+    }
+}
+
+impl<T> Matrix<4, 4, T>
+where
+    T: Copy + Zero + One,
+{
+    /// Create the homogeneous transform that translates points by
+    /// `(x, y, z)`.
+    pub fn translation(x: T, y: T, z: T) -> Self {
+        Self::from_rotation_translation(
+            &Matrix::eye(),
+            &Matrix::from_column_major_order([[x, y, z]]),
+        )
+    }
+
+    /// Create the homogeneous transform that scales points by `(x, y, z)`
+    /// along the coordinate axes.
+    pub fn scaling(x: T, y: T, z: T) -> Self {
+        let mut H = Self::eye();
+        H[(0, 0)] = x;
+        H[(1, 1)] = y;
+        H[(2, 2)] = z;
+        H
+    }
+
+    /// Assemble the homogeneous transform with rotation `r` followed by the
+    /// translation `t`, i.e.
+    ///
+    /// ```text
+    /// ⎡ R t ⎤
+    /// ⎣ 0 1 ⎦
+    /// ```
+    pub fn from_rotation_translation(r: &Matrix<3, 3, T>, t: &Vector<3, T>) -> Self {
+        let mut H = Self::eye();
+        H.set_submatrix(0, 0, r);
+        H.set_submatrix(0, 3, t);
+        H
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use crate::{matrix, vector, Matrix};
+
+    #[test]
+    fn rotation_angle() {
+        for theta in [0.0, 0.3, -1.2, 2.5, -3.0] {
+            let R = Matrix::<2, 2, f64>::rotation(theta);
+            assert_relative_eq!(R.rotation_angle(), theta, epsilon = 1e-12);
+        }
+        let R = Matrix::<2, 2, f64>::rotation(core::f64::consts::FRAC_PI_2);
+        assert_relative_eq!(R, matrix![0.0, -1.0; 1.0, 0.0], epsilon = 1e-12);
+    }
+
+    /// `Rz(yaw) Ry(pitch) Rx(roll)` from the elementary rotations.
+    fn rpy(roll: f64, pitch: f64, yaw: f64) -> Matrix<3, 3, f64> {
+        let (sr, cr) = (libm::sin(roll), libm::cos(roll));
+        let (sp, cp) = (libm::sin(pitch), libm::cos(pitch));
+        let (sy, cy) = (libm::sin(yaw), libm::cos(yaw));
+        let Rx = matrix![
+            1.0, 0.0, 0.0;
+            0.0,  cr, -sr;
+            0.0,  sr,  cr;
+        ];
+        let Ry = matrix![
+             cp, 0.0,  sp;
+            0.0, 1.0, 0.0;
+            -sp, 0.0,  cp;
+        ];
+        let Rz = matrix![
+             cy, -sy, 0.0;
+             sy,  cy, 0.0;
+            0.0, 0.0, 1.0;
+        ];
+        Rz * Ry * Rx
+    }
+
+    #[test]
+    fn euler_angles() {
+        let (roll, pitch, yaw) = (0.3, -0.4, 1.1);
+        let (r, p, y) = rpy(roll, pitch, yaw).euler_angles();
+        assert_relative_eq!(r, roll, epsilon = 1e-12);
+        assert_relative_eq!(p, pitch, epsilon = 1e-12);
+        assert_relative_eq!(y, yaw, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn from_euler_xyz() {
+        for (roll, pitch, yaw) in [(0.3, -0.4, 1.1), (-2.0, 1.2, 3.0), (2.9, -1.5, -0.7)] {
+            let R = Matrix::from_euler_xyz(roll, pitch, yaw);
+            assert_relative_eq!(R, rpy(roll, pitch, yaw), epsilon = 1e-12);
+            let (r, p, y) = R.to_euler_xyz();
+            assert_relative_eq!(r, roll, epsilon = 1e-12);
+            assert_relative_eq!(p, pitch, epsilon = 1e-12);
+            assert_relative_eq!(y, yaw, epsilon = 1e-12);
+        }
+
+        // Right-handed: yaw by 90° takes x onto y
+        let R = Matrix::from_euler_xyz(0.0, 0.0, core::f64::consts::FRAC_PI_2);
+        assert_relative_eq!(
+            R * vector![1.0; 0.0; 0.0],
+            vector![0.0; 1.0; 0.0],
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn to_euler_xyz() {
+        for (roll, pitch, yaw) in [(0.0, 0.0, 0.0), (-2.0, 1.2, 3.0), (2.9, -1.5, -0.7)] {
+            let (r, p, y) = rpy(roll, pitch, yaw).to_euler_xyz();
+            assert_relative_eq!(r, roll, epsilon = 1e-12);
+            assert_relative_eq!(p, pitch, epsilon = 1e-12);
+            assert_relative_eq!(y, yaw, epsilon = 1e-12);
+        }
+
+        // Gimbal lock: only roll - yaw (pitch up) or roll + yaw (pitch down)
+        // is determined, and it is reported as roll
+        let half_pi = core::f64::consts::FRAC_PI_2;
+        let (r, p, y) = rpy(0.5, half_pi, 0.2).to_euler_xyz();
+        assert_relative_eq!(r, 0.3, epsilon = 1e-12);
+        assert_relative_eq!(p, half_pi, epsilon = 1e-12);
+        assert_eq!(y, 0.0);
+        assert_relative_eq!(rpy(r, p, y), rpy(0.5, half_pi, 0.2), epsilon = 1e-12);
+
+        let (r, p, y) = rpy(0.5, -half_pi, 0.2).to_euler_xyz();
+        assert_relative_eq!(r, 0.7, epsilon = 1e-12);
+        assert_relative_eq!(p, -half_pi, epsilon = 1e-12);
+        assert_eq!(y, 0.0);
+
+        // Pitch stays accurate just short of gimbal lock
+        let pitch = half_pi - 1e-7;
+        let (r, p, y) = rpy(0.5, pitch, 0.2).to_euler_xyz();
+        assert_relative_eq!(p, pitch, epsilon = 1e-15);
+        assert_relative_eq!(rpy(r, p, y), rpy(0.5, pitch, 0.2), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn is_rotation() {
+        let R = Matrix::from_euler_xyz(0.3, -0.4, 1.1);
+        assert!(R.is_orthogonal(1e-12));
+        assert!(R.is_rotation(1e-12));
+
+        // Reflection through the xy-plane is orthogonal with det = -1
+        let F = R * matrix![
+            1.0, 0.0,  0.0;
+            0.0, 1.0,  0.0;
+            0.0, 0.0, -1.0;
+        ];
+        assert!(F.is_orthogonal(1e-12));
+        assert!(!F.is_rotation(1e-12));
+
+        // A scaled rotation is not orthogonal
+        assert!(!(R * 1.1).is_orthogonal(1e-12));
+        assert!(!(R * 1.1).is_rotation(1e-12));
+
+        // Small perturbations are accepted within tolerance
+        let mut P = R;
+        P[(0, 0)] += 1e-9;
+        assert!(!P.is_rotation(1e-12));
+        assert!(P.is_rotation(1e-6));
+    }
+
+    #[test]
+    fn average_rotations() {
+        // Rotations about the same axis average to the mean angle
+        let rots = [0.1, 0.2, 0.3, 0.4].map(|yaw| Matrix::from_euler_xyz(0.0, 0.0, yaw));
+        let R = Matrix::average_rotations(&rots).unwrap();
+        assert!(R.is_rotation(1e-12));
+        assert_relative_eq!(R, Matrix::from_euler_xyz(0.0, 0.0, 0.25), epsilon = 1e-12);
+
+        let R = Matrix::<3, 3, f64>::average_rotations(&[Matrix::from_euler_xyz(0.3, -0.4, 1.1)])
+            .unwrap();
+        assert_relative_eq!(R, Matrix::from_euler_xyz(0.3, -0.4, 1.1), epsilon = 1e-12);
+
+        // Noisy estimates of the same orientation
+        let rots = [
+            Matrix::from_euler_xyz(0.52, 0.29, -0.98),
+            Matrix::from_euler_xyz(0.47, 0.31, -1.01),
+            Matrix::from_euler_xyz(0.50, 0.30, -1.00),
+        ];
+        let R = Matrix::average_rotations(&rots).unwrap();
+        assert!(R.is_rotation(1e-12));
+        let (r, p, y) = R.to_euler_xyz();
+        assert_relative_eq!(r, 0.5, epsilon = 2e-2);
+        assert_relative_eq!(p, 0.3, epsilon = 2e-2);
+        assert_relative_eq!(y, -1.0, epsilon = 2e-2);
+
+        // Opposite rotations cancel out and have no unique mean
+        let pi = core::f64::consts::PI;
+        let rots = [Matrix::eye(), Matrix::from_euler_xyz(0.0, 0.0, pi)];
+        assert_eq!(Matrix::average_rotations(&rots), None);
+        let rots = [
+            Matrix::eye(),
+            Matrix::from_euler_xyz(pi, 0.0, 0.0),
+            Matrix::from_euler_xyz(0.0, pi, 0.0),
+            Matrix::from_euler_xyz(0.0, 0.0, pi),
+        ];
+        assert_eq!(Matrix::average_rotations(&rots), None);
+    }
+
+    #[test]
+    fn axis_angle() {
+        let pi = core::f64::consts::PI;
+        let n = libm::sqrt(14.0);
+        let axis = vector![1.0 / n; -2.0 / n; 3.0 / n];
+        for angle in [1e-9, 0.5, 1.5, 2.0, 3.0, pi - 1e-9] {
+            let R = Matrix::from_axis_angle(&axis, angle);
+            assert!(R.is_rotation(1e-12));
+            let (k, theta) = R.to_axis_angle();
+            assert_relative_eq!(theta, angle, epsilon = 1e-12);
+            assert_relative_eq!(k, axis, epsilon = 1e-6);
+        }
+
+        // Right-handed: a quarter turn about z takes x onto y
+        let z = vector![0.0; 0.0; 1.0];
+        let R = Matrix::from_axis_angle(&z, pi / 2.0);
+        assert_relative_eq!(
+            R,
+            Matrix::from_euler_xyz(0.0, 0.0, pi / 2.0),
+            epsilon = 1e-12
+        );
+
+        // Half turn: the axis is only determined up to sign
+        let R = Matrix::from_axis_angle(&axis, pi);
+        let (k, theta) = R.to_axis_angle();
+        assert_relative_eq!(theta, pi, epsilon = 1e-12);
+        assert_relative_eq!(Matrix::from_axis_angle(&k, theta), R, epsilon = 1e-12);
+
+        // Identity
+        let (k, theta) = Matrix::<3, 3, f64>::eye().to_axis_angle();
+        assert_eq!(theta, 0.0);
+        assert_eq!(k, vector![1.0; 0.0; 0.0]);
+    }
+
+    #[test]
+    fn angular_distance() {
+        let R = Matrix::from_euler_xyz(0.3, -0.4, 1.1);
+        assert_eq!(R.angular_distance(&R), 0.0);
+
+        let a = Matrix::from_euler_xyz(0.0, 0.0, 0.1);
+        let b = Matrix::from_euler_xyz(0.0, 0.0, 0.4);
+        assert_relative_eq!(a.angular_distance(&b), 0.3, epsilon = 1e-12);
+        assert_relative_eq!(b.angular_distance(&a), 0.3, epsilon = 1e-12);
+
+        let n = libm::sqrt(3.0);
+        let axis = vector![1.0 / n; 1.0 / n; -1.0 / n];
+        let S = R * Matrix::from_axis_angle(&axis, 2.5);
+        assert_relative_eq!(R.angular_distance(&S), 2.5, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn homogeneous() {
+        let p = vector![1.0; 2.0; 3.0; 1.0];
+
+        let T = Matrix::translation(1.0, -2.0, 0.5);
+        assert_eq!(T * p, vector![2.0; 0.0; 3.5; 1.0]);
+
+        let S = Matrix::scaling(2.0, 3.0, -1.0);
+        assert_eq!(S * p, vector![2.0; 6.0; -3.0; 1.0]);
+
+        // A quarter turn about z followed by a translation
+        let R = Matrix::from_axis_angle(&vector![0.0; 0.0; 1.0], core::f64::consts::FRAC_PI_2);
+        let H = Matrix::from_rotation_translation(&R, &vector![10.0; 0.0; 0.0]);
+        assert_relative_eq!(H * p, vector![8.0; 1.0; 3.0; 1.0], epsilon = 1e-12);
+        assert_eq!(H.submatrix::<3, 3>(0, 0), R);
+        assert_eq!(H.row_vector(3), matrix![0.0, 0.0, 0.0, 1.0]);
+        let Rh = Matrix::from_rotation_translation(&R, &Matrix::zeros());
+        assert_eq!(Matrix::translation(10.0, 0.0, 0.0) * Rh, H);
+    }
+}
@@ -1,10 +1,13 @@
 //! Row and column slices of a matrix.
 
 use core::iter::Sum;
-use core::ops::{Deref, DerefMut, Mul};
+use core::ops::{Deref, DerefMut, Index, Mul};
 
 use stride::Stride;
 
+use crate::num::Zero;
+use crate::Matrix;
+
 ////////////////////////////////////////////////////////////////////////////////
 // Row
 ////////////////////////////////////////////////////////////////////////////////
@@ -134,6 +137,94 @@ where
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Transposed
+////////////////////////////////////////////////////////////////////////////////
+
+/// A borrowed view of the transpose of an `M`-by-`N` [`Matrix`], behaving
+/// like an `N`-by-`M` matrix without copying any elements.
+///
+/// Created by [`Matrix::transposed`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Transposed<'a, const M: usize, const N: usize, T> {
+    matrix: &'a Matrix<M, N, T>,
+}
+
+// Not derived, since that would require `T: Clone` for what is only a borrow
+impl<T, const M: usize, const N: usize> Clone for Transposed<'_, M, N, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const M: usize, const N: usize> Copy for Transposed<'_, M, N, T> {}
+
+impl<'a, T, const M: usize, const N: usize> Transposed<'a, M, N, T> {
+    pub(crate) fn new(matrix: &'a Matrix<M, N, T>) -> Self {
+        Self { matrix }
+    }
+
+    /// Returns a reference to the `i`-th row of the transpose, which is the
+    /// `i`-th column of the underlying matrix.
+    #[inline]
+    pub fn row(&self, i: usize) -> &'a Column<M, N, T> {
+        self.matrix.column(i)
+    }
+
+    /// Returns a reference to the `i`-th column of the transpose, which is the
+    /// `i`-th row of the underlying matrix.
+    #[inline]
+    pub fn column(&self, i: usize) -> &'a Row<M, N, T> {
+        self.matrix.row(i)
+    }
+
+    /// Materialize the transpose as a new matrix.
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix<N, M, T>
+    where
+        T: Clone,
+    {
+        self.matrix.transpose()
+    }
+}
+
+impl<T, const M: usize, const N: usize> Index<(usize, usize)> for Transposed<'_, M, N, T> {
+    type Output = T;
+
+    #[track_caller]
+    #[inline]
+    fn index(&self, (r, c): (usize, usize)) -> &T {
+        &self.matrix[(c, r)]
+    }
+}
+
+macro_rules! impl_transposed_mul {
+    ($rhs:ty) => {
+        impl<T, const M: usize, const N: usize, const P: usize> Mul<$rhs>
+            for Transposed<'_, M, N, T>
+        where
+            T: Copy + Zero + Mul<Output = T> + Sum,
+        {
+            type Output = Matrix<N, P, T>;
+
+            fn mul(self, rhs: $rhs) -> Self::Output {
+                let mut matrix = Self::Output::zeros();
+                for j in 0..P {
+                    let b = rhs.column(j);
+                    for i in 0..N {
+                        let a = self.matrix.column(i);
+                        matrix[(i, j)] = (0..M).map(|k| a[k] * b[k]).sum();
+                    }
+                }
+                matrix
+            }
+        }
+    };
+}
+
+impl_transposed_mul! {  Matrix<M,P,T> }
+impl_transposed_mul! { &Matrix<M,P,T> }
+
 ////////////////////////////////////////////////////////////////////////////////
 // General
 ////////////////////////////////////////////////////////////////////////////////
@@ -195,3 +286,48 @@ fn dot_partial() {
     let d = m.row(1).dot_partial(m.column(2), 1..3);
     assert_eq!(d, 126.0);
 }
+
+#[test]
+fn transposed() {
+    use super::*;
+    let m = matrix![
+        1.0, 2.0;
+        3.0, 4.0;
+        5.0, 6.0;
+    ];
+    let t = m.transposed();
+    let e = m.transpose();
+    assert_eq!(t.to_matrix(), e);
+    for r in 0..2 {
+        assert_eq!(t.row(r), &**e.row(r));
+        for c in 0..3 {
+            assert_eq!(t[(r, c)], e[(r, c)]);
+        }
+    }
+    for c in 0..3 {
+        assert_eq!(t.column(c), &**e.column(c));
+    }
+
+    let v = vector![1.0; -1.0; 2.0];
+    assert_eq!(t * v, e * v);
+    let b = matrix![
+        1.0, 0.0, 2.0, -1.0;
+        0.5, 3.0, 0.0,  1.0;
+        2.0, 1.0, 1.0,  0.0;
+    ];
+    assert_eq!(m.transposed() * b, e * b);
+}
+
+#[test]
+fn transposed_copy() {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct NotCopy(i32);
+
+    let m = Matrix::from_column_major_order([[NotCopy(1), NotCopy(2)], [NotCopy(3), NotCopy(4)]]);
+    let t = m.transposed();
+    let u = t;
+    assert_eq!(t[(0, 1)], NotCopy(2));
+    assert_eq!(u[(1, 0)], NotCopy(3));
+}